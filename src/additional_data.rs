@@ -0,0 +1,134 @@
+//! Structured additional data.
+//!
+//! Proving and verification procedures take the additional data as a plain byte
+//! string. When it is built from several fields, naive concatenation is ambiguous
+//! (e.g. `["ab", "c"]` and `["a", "bc"]` collide). `AdditionalData` encodes each
+//! field as `len(field) || field`, with the length as 4 bytes little endian, and
+//! caps the total encoded size to `AdditionalData::MAX_LEN`.
+//!
+//! As it implements `AsRef<[u8]>`, it can be passed wherever `ad` is expected.
+//!
+//! ```rust,ignore
+//! let ad = AdditionalData::from_fields([&b"block"[..], &block_hash, &slot.to_le_bytes()])?;
+//! let proof = secret.prove(input, output, &ad);
+//! ```
+
+use crate::*;
+
+/// Length prefixed additional data.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AdditionalData(Vec<u8>);
+
+impl AdditionalData {
+    /// Max encoded length.
+    pub const MAX_LEN: usize = 1 << 16;
+
+    /// Construct an empty additional data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct from the given fields.
+    ///
+    /// Returns `Error::InvalidData` if the encoded length exceeds `MAX_LEN`.
+    pub fn from_fields<I>(fields: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        fields
+            .into_iter()
+            .try_fold(Self::new(), |ad, field| ad.with(field))
+    }
+
+    /// Append a field, builder style.
+    ///
+    /// Returns `Error::InvalidData` if the encoded length exceeds `MAX_LEN`.
+    pub fn with(mut self, field: impl AsRef<[u8]>) -> Result<Self, Error> {
+        self.push(field)?;
+        Ok(self)
+    }
+
+    /// Append a field.
+    ///
+    /// Returns `Error::InvalidData` if the encoded length exceeds `MAX_LEN`.
+    /// On failure the additional data is left untouched.
+    pub fn push(&mut self, field: impl AsRef<[u8]>) -> Result<(), Error> {
+        let field = field.as_ref();
+        if self.0.len() + 4 + field.len() > Self::MAX_LEN {
+            return Err(Error::InvalidData);
+        }
+        self.0.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        self.0.extend_from_slice(field);
+        Ok(())
+    }
+
+    /// Encoded bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Encoded length.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if no field has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for AdditionalData {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn fields_are_unambiguous() {
+        let ad1 = AdditionalData::from_fields([&b"ab"[..], b"c"]).unwrap();
+        let ad2 = AdditionalData::from_fields([&b"a"[..], b"bc"]).unwrap();
+        assert_ne!(ad1, ad2);
+        assert_eq!(ad1.as_bytes(), b"\x02\x00\x00\x00ab\x01\x00\x00\x00c");
+
+        let ad3 = AdditionalData::new().with(b"ab").unwrap().with(b"c").unwrap();
+        assert_eq!(ad1, ad3);
+
+        // Empty fields are encoded too.
+        assert!(AdditionalData::new().is_empty());
+        assert_eq!(AdditionalData::from_fields([b""]).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn max_len() {
+        let field = ark_std::vec![0; AdditionalData::MAX_LEN - 4];
+        let mut ad = AdditionalData::from_fields([&field]).unwrap();
+        assert_eq!(ad.len(), AdditionalData::MAX_LEN);
+        assert_eq!(ad.push(b""), Err(Error::InvalidData));
+        assert_eq!(ad.len(), AdditionalData::MAX_LEN);
+    }
+
+    #[cfg(feature = "ietf")]
+    #[test]
+    fn prove_verify_with_additional_data() {
+        use crate::ietf::{Prover, Verifier};
+        use crate::suites::testing::{Input, Secret};
+        use crate::testing::TEST_SEED;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let input = Input::new(b"foo").unwrap();
+        let output = secret.output(input);
+        let ad = AdditionalData::from_fields([&b"ab"[..], b"c"]).unwrap();
+
+        let proof = secret.prove(input, output, &ad);
+        assert!(secret.public().verify(input, output, &ad, &proof).is_ok());
+        let other = AdditionalData::from_fields([&b"a"[..], b"bc"]).unwrap();
+        assert!(secret.public().verify(input, output, &other, &proof).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+//! Runtime selectable suites.
+//!
+//! Wraps the built-in suites enabled via crate features into enums, so that
+//! the suite can be chosen at runtime (e.g. from configuration). Signatures
+//! are IETF VRF proofs, encoded as RFC-9381 `pi_string`, with VRF input built
+//! via `Input::new_salted`.
+
+use crate::ietf::{Prover, Verifier};
+use crate::*;
+
+macro_rules! any_suites {
+    ($($(#[$meta:meta])* $variant:ident => $suite:ty),* $(,)?) => {
+        /// Built-in suite selectable at runtime.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum AnySuite {
+            $($(#[$meta])* $variant,)*
+        }
+
+        /// Secret key of any built-in suite.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum AnySecret {
+            $($(#[$meta])* $variant(Secret<$suite>),)*
+        }
+
+        /// Public key of any built-in suite.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum AnyPublic {
+            $($(#[$meta])* $variant(Public<$suite>),)*
+        }
+
+        /// VRF output and IETF proof of any built-in suite.
+        #[derive(Debug, Clone)]
+        pub enum AnySignature {
+            $($(#[$meta])* $variant(Output<$suite>, ietf::Proof<$suite>),)*
+        }
+
+        impl AnySuite {
+            /// All the suites enabled via crate features.
+            pub const ALL: &'static [AnySuite] = &[$($(#[$meta])* AnySuite::$variant,)*];
+
+            /// Suite identifier.
+            pub fn suite_id(self) -> &'static [u8] {
+                match self {
+                    $($(#[$meta])* Self::$variant => <$suite as Suite>::SUITE_ID,)*
+                }
+            }
+
+            /// Lookup the suite given its identifier.
+            pub fn from_suite_id(suite_id: &[u8]) -> Option<Self> {
+                Self::ALL.iter().copied().find(|s| s.suite_id() == suite_id)
+            }
+
+            /// Construct a secret key from the given seed (see `Secret::from_seed`).
+            pub fn secret_from_seed(self, seed: &[u8]) -> AnySecret {
+                match self {
+                    $($(#[$meta])* Self::$variant => {
+                        AnySecret::$variant(Secret::from_seed(seed))
+                    })*
+                }
+            }
+
+            /// Decode a public key encoded via `AnyPublic::to_bytes`.
+            pub fn public_from_bytes(self, buf: &[u8]) -> Result<AnyPublic, Error> {
+                match self {
+                    $($(#[$meta])* Self::$variant => {
+                        Ok(AnyPublic::$variant(Public::<$suite>::from_bytes(buf)?))
+                    })*
+                }
+            }
+
+            /// Decode a signature encoded via `AnySignature::to_bytes`.
+            pub fn signature_from_bytes(self, buf: &[u8]) -> Result<AnySignature, Error> {
+                match self {
+                    $($(#[$meta])* Self::$variant => {
+                        let (output, proof) = ietf::Proof::<$suite>::from_pi_string(buf)?;
+                        Ok(AnySignature::$variant(output, proof))
+                    })*
+                }
+            }
+        }
+
+        impl AnySecret {
+            /// Secret key suite.
+            pub fn suite(&self) -> AnySuite {
+                match *self {
+                    $($(#[$meta])* Self::$variant(_) => AnySuite::$variant,)*
+                }
+            }
+
+            /// Public key associated to the secret.
+            pub fn public(&self) -> AnyPublic {
+                match *self {
+                    $($(#[$meta])* Self::$variant(ref sk) => AnyPublic::$variant(sk.public()),)*
+                }
+            }
+
+            /// Sign `alpha` and additional data `ad`.
+            ///
+            /// Returns `Error::InvalidData` if `alpha` can't be mapped to a VRF input.
+            pub fn sign(&self, alpha: &[u8], ad: &[u8]) -> Result<AnySignature, Error> {
+                match *self {
+                    $($(#[$meta])* Self::$variant(ref sk) => {
+                        let input = Input::new_salted(&sk.public(), alpha)
+                            .ok_or(Error::InvalidData)?;
+                        let output = sk.output(input);
+                        let proof = sk.prove(input, output, ad);
+                        Ok(AnySignature::$variant(output, proof))
+                    })*
+                }
+            }
+        }
+
+        impl AnyPublic {
+            /// Public key suite.
+            pub fn suite(&self) -> AnySuite {
+                match *self {
+                    $($(#[$meta])* Self::$variant(_) => AnySuite::$variant,)*
+                }
+            }
+
+            /// Encode the public key point using the suite codec.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match *self {
+                    $($(#[$meta])* Self::$variant(ref pk) => pk.to_bytes(),)*
+                }
+            }
+
+            /// Verify a signature of `alpha` and additional data `ad`.
+            ///
+            /// On success, returns the VRF output hash. Returns
+            /// `Error::IncompatibleFormat` if the signature suite doesn't match.
+            #[allow(unreachable_patterns)]
+            pub fn verify(
+                &self,
+                alpha: &[u8],
+                ad: &[u8],
+                signature: &AnySignature,
+            ) -> Result<Vec<u8>, Error> {
+                match (self, signature) {
+                    $($(#[$meta])* (Self::$variant(pk), AnySignature::$variant(output, proof)) => {
+                        let input = Input::new_salted(pk, alpha).ok_or(Error::InvalidData)?;
+                        pk.verify(input, *output, ad, proof)?;
+                        Ok(output.hash().to_vec())
+                    })*
+                    _ => Err(Error::IncompatibleFormat),
+                }
+            }
+        }
+
+        impl AnySignature {
+            /// Signature suite.
+            pub fn suite(&self) -> AnySuite {
+                match *self {
+                    $($(#[$meta])* Self::$variant(..) => AnySuite::$variant,)*
+                }
+            }
+
+            /// VRF output hash.
+            pub fn output_hash(&self) -> Vec<u8> {
+                match *self {
+                    $($(#[$meta])* Self::$variant(ref output, _) => output.hash().to_vec(),)*
+                }
+            }
+
+            /// Encode as RFC-9381 `pi_string` (see `ietf::Proof::to_pi_string`).
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match *self {
+                    $($(#[$meta])* Self::$variant(ref output, ref proof) => {
+                        proof.to_pi_string(output)
+                    })*
+                }
+            }
+        }
+    };
+}
+
+any_suites!(
+    #[cfg(feature = "ed25519")]
+    Ed25519Sha512Tai => suites::ed25519::Ed25519Sha512Tai,
+    #[cfg(feature = "ed25519")]
+    Ed25519Sha512TaiRfc9381 => suites::ed25519_rfc_9381::Ed25519Sha512TaiRfc9381,
+    #[cfg(feature = "ed25519")]
+    Ed25519Sha512Ell2 => suites::ed25519_ell2::Ed25519Sha512Ell2,
+    #[cfg(feature = "ed25519")]
+    Ristretto255Sha512 => suites::ristretto255::Ristretto255Sha512,
+    #[cfg(feature = "secp256r1")]
+    P256Sha256Tai => suites::secp256::P256Sha256Tai,
+    #[cfg(feature = "secp256r1")]
+    P256Sha256Sswu => suites::secp256_sswu::P256Sha256Sswu,
+    #[cfg(feature = "secp384r1")]
+    P384Sha384Tai => suites::secp384::P384Sha384Tai,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Sha256Tai => suites::secp256k1::Secp256k1Sha256Tai,
+    #[cfg(feature = "keccak")]
+    Secp256k1Keccak256Tai => suites::secp256k1_keccak::Secp256k1Keccak256Tai,
+    #[cfg(feature = "bandersnatch")]
+    BandersnatchSha512Tai => suites::bandersnatch::weierstrass::BandersnatchSha512Tai,
+    #[cfg(feature = "bandersnatch")]
+    BandersnatchSha512Ell2 => suites::bandersnatch::edwards::BandersnatchSha512Ell2,
+    #[cfg(feature = "poseidon")]
+    BandersnatchPoseidon => suites::bandersnatch::poseidon::BandersnatchPoseidon,
+    #[cfg(feature = "jubjub")]
+    JubjubSha512Tai => suites::jubjub::JubjubSha512Tai,
+    #[cfg(feature = "baby-jubjub")]
+    BabyJubjubKeccak256Tai => suites::baby_jubjub::BabyJubjubKeccak256Tai,
+    #[cfg(all(feature = "baby-jubjub", feature = "poseidon"))]
+    BabyJubjubPoseidon => suites::baby_jubjub::poseidon::BabyJubjubPoseidon,
+    #[cfg(feature = "pasta")]
+    PallasBlake2b512Tai => suites::pasta::pallas::PallasBlake2b512Tai,
+    #[cfg(feature = "pasta")]
+    VestaBlake2b512Tai => suites::pasta::vesta::VestaBlake2b512Tai,
+    #[cfg(feature = "bls12-381")]
+    Bls12_381G1Sha512Tai => suites::bls12_381::Bls12_381G1Sha512Tai,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_SEED;
+
+    #[test]
+    fn any_suite_sign_verify() {
+        for &suite in AnySuite::ALL {
+            assert_eq!(AnySuite::from_suite_id(suite.suite_id()), Some(suite));
+
+            let secret = suite.secret_from_seed(TEST_SEED);
+            let public = suite.public_from_bytes(&secret.public().to_bytes()).unwrap();
+            assert_eq!(public, secret.public());
+
+            let signature = secret.sign(b"alpha", b"foo").unwrap();
+            assert_eq!(signature.suite(), suite);
+            let signature = suite.signature_from_bytes(&signature.to_bytes()).unwrap();
+            let hash = public.verify(b"alpha", b"foo", &signature).unwrap();
+            assert_eq!(hash, signature.output_hash());
+
+            assert!(public.verify(b"alpha", b"bar", &signature).is_err());
+            assert!(public.verify(b"beta", b"foo", &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn any_suite_mismatch() {
+        let [a, b, ..] = AnySuite::ALL else {
+            return;
+        };
+        let signature = a.secret_from_seed(TEST_SEED).sign(b"alpha", b"").unwrap();
+        let public = b.secret_from_seed(TEST_SEED).public();
+        let res = public.verify(b"alpha", b"", &signature);
+        assert!(matches!(res, Err(Error::IncompatibleFormat)));
+    }
+}
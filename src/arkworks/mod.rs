@@ -2,5 +2,7 @@
 
 /// Elligator 2 hash-to-curve.
 pub mod elligator2;
+/// Simplified SWU hash-to-curve.
+pub mod sswu;
 /// Twisted Edwards to Short Weierstrass mapping.
 pub mod te_sw_map;
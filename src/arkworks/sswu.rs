@@ -0,0 +1,105 @@
+//! Simplified Shallue-van de Woestijne-Ulas (SSWU) map for Short Weierstrass curves.
+//!
+//! Arkworks provides the same map, but its `SWUConfig` can't be implemented
+//! outside the crates defining the curves configurations (orphan rule).
+//!
+//! Reference: [RFC-9380](https://datatracker.ietf.org/doc/rfc9380) section 6.6.2.
+
+use ark_ec::{
+    hashing::{curve_maps::swu::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+};
+use ark_ff::{Field, One, Zero};
+use core::marker::PhantomData;
+
+/// Trait defining the necessary parameters for the SSWU hash-to-curve method
+/// for Short Weierstrass curves of the form `y² = x³ + a * x + b` with `a * b != 0`.
+pub trait SWUConfig: SWCurveConfig {
+    /// An element of the base field that is not a square root.
+    /// When `BaseField` is a prime field, RFC-9380 mandates `Z` to satisfy the
+    /// criteria given in section 6.6.2 (e.g. `Z = -10` for P-256).
+    const ZETA: Self::BaseField;
+}
+
+/// Represents the SSWU hash-to-curve map defined by `P`.
+pub struct SWUMap<P: SWUConfig>(PhantomData<fn() -> P>);
+
+impl<P: SWUConfig> SWUMap<P> {
+    /// Checks if `P` represents a valid SSWU map. Panics otherwise.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        debug_assert!(
+            !P::ZETA.legendre().is_qr(),
+            "ZETA should be a quadratic non-residue for the SSWU map"
+        );
+        debug_assert!(
+            !P::COEFF_A.is_zero() && !P::COEFF_B.is_zero(),
+            "Simplified SWU requires a * b != 0 in the Short Weierstrass form"
+        );
+        Ok(())
+    }
+}
+
+impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        Self::check_parameters()?;
+        Ok(SWUMap(PhantomData))
+    }
+
+    fn map_to_curve(&self, element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        // 1. tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        // 2. x1 = (-B / A) * (1 + tv1)
+        // 3. If tv1 == 0, set x1 = B / (Z * A)
+        // 4. gx1 = x1^3 + A * x1 + B
+        // 5. x2 = Z * u^2 * x1
+        // 6. gx2 = x2^3 + A * x2 + B
+        // 7. If is_square(gx1), set x = x1 and y = sqrt(gx1)
+        // 8. Else set x = x2 and y = sqrt(gx2)
+        // 9. If sgn0(u) != sgn0(y), set y = -y
+        // 10. return (x, y)
+        //
+        // Fractions are kept as numerator and denominator until the end, and
+        // sqrt(gx2) is computed from sqrt(Z * gx1) as gx2 = (Z * u^2)^3 * gx1.
+        let a = P::COEFF_A;
+        let b = P::COEFF_B;
+
+        let zeta_u2 = P::ZETA * element.square();
+        let ta = zeta_u2.square() + zeta_u2;
+        let num_x1 = b * (ta + <P::BaseField as One>::one());
+        let div = a * if ta.is_zero() { P::ZETA } else { -ta };
+
+        let num2_x1 = num_x1.square();
+        let div2 = div.square();
+        let div3 = div2 * div;
+        let num_gx1 = (num2_x1 + a * div2) * num_x1 + b * div3;
+
+        let num_x2 = zeta_u2 * num_x1;
+
+        // A and Z are not zero, thus div is not zero.
+        let gx1 = num_gx1 / div3;
+        let (num_x, y) = if gx1.legendre().is_qr() {
+            let y1 = gx1
+                .sqrt()
+                .expect("We have checked that gx1 is a quadratic residue. Q.E.D.");
+            (num_x1, y1)
+        } else {
+            let y1 = (P::ZETA * gx1)
+                .sqrt()
+                .expect("Z * gx1 is a quadratic residue because gx1 is not. Q.E.D.");
+            (num_x2, zeta_u2 * element * y1)
+        };
+
+        let x = num_x / div;
+        let y = if parity(&y) != parity(&element) {
+            -y
+        } else {
+            y
+        };
+
+        let point = Affine::<P>::new_unchecked(x, y);
+        debug_assert!(
+            point.is_on_curve(),
+            "SSWU mapped point is expected to be on the curve"
+        );
+        Ok(point)
+    }
+}
@@ -48,10 +48,26 @@ pub fn map_te_to_sw<C: MapConfig>(point: &EdwardsAffine<C>) -> Option<Weierstras
 }
 
 pub trait SWMapping<C: SWCurveConfig> {
+    /// Map from SW form.
+    ///
+    /// Panics if the point can't be mapped (see `try_from_sw`).
     fn from_sw(sw: WeierstrassAffine<C>) -> Self;
 
+    /// Map into SW form.
+    ///
+    /// Panics if the point can't be mapped (see `try_into_sw`).
     fn into_sw(self) -> WeierstrassAffine<C>;
 
+    /// Map from SW form, returning `None` for exceptional points of the map
+    /// (e.g. the identity and the 2-torsion points).
+    fn try_from_sw(sw: WeierstrassAffine<C>) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Map into SW form, returning `None` for exceptional points of the map
+    /// (e.g. the identity and the 2-torsion points).
+    fn try_into_sw(self) -> Option<WeierstrassAffine<C>>;
+
     fn to_sw_slice(slice: &[Self]) -> Cow<[WeierstrassAffine<C>]>
     where
         Self: Sized;
@@ -68,6 +84,16 @@ impl<C: SWCurveConfig> SWMapping<C> for WeierstrassAffine<C> {
         self
     }
 
+    #[inline(always)]
+    fn try_from_sw(sw: WeierstrassAffine<C>) -> Option<Self> {
+        Some(sw)
+    }
+
+    #[inline(always)]
+    fn try_into_sw(self) -> Option<WeierstrassAffine<C>> {
+        Some(self)
+    }
+
     #[inline(always)]
     fn to_sw_slice(slice: &[Self]) -> Cow<[WeierstrassAffine<C>]> {
         Cow::Borrowed(slice)
@@ -89,6 +115,16 @@ impl<C: MapConfig> SWMapping<C> for EdwardsAffine<C> {
         map_te_to_sw(&self).expect(ERR_MSG)
     }
 
+    #[inline(always)]
+    fn try_from_sw(sw: WeierstrassAffine<C>) -> Option<Self> {
+        map_sw_to_te(&sw)
+    }
+
+    #[inline(always)]
+    fn try_into_sw(self) -> Option<WeierstrassAffine<C>> {
+        map_te_to_sw(&self)
+    }
+
     #[inline(always)]
     fn to_sw_slice(slice: &[Self]) -> Cow<[WeierstrassAffine<C>]> {
         let pks;
@@ -4,19 +4,23 @@ use arkworks::te_sw_map;
 use super::*;
 
 /// Defines points and scalars encoding format.
+///
+/// Selected per suite via `Suite::Codec`. All the crate encodings (proofs,
+/// hashing, test vectors, etc.) go through the suite codec.
 pub trait Codec<S: Suite> {
+    /// Scalars (and points coordinates) are big endian encoded.
     const BIG_ENDIAN: bool;
 
-    /// Point encode.
+    /// Point encode (aka `point_to_string` in RFC-9381).
     fn point_encode(pt: &AffinePoint<S>, buf: &mut Vec<u8>);
 
-    /// Point decode.
+    /// Point decode (aka `string_to_point` in RFC-9381).
     fn point_decode(buf: &[u8]) -> Result<AffinePoint<S>, Error>;
 
-    /// Scalar encode
+    /// Scalar encode (aka `int_to_string` in RFC-9381).
     fn scalar_encode(sc: &ScalarField<S>, buf: &mut Vec<u8>);
 
-    /// Scalar decode.
+    /// Scalar decode (aka `string_to_int` in RFC-9381).
     fn scalar_decode(buf: &[u8]) -> ScalarField<S>;
 }
 
@@ -87,18 +91,21 @@ where
         }
         let mut buf = buf.to_vec();
         buf.reverse();
-        let y_flag = buf.pop().unwrap();
+        let y_flag = match buf.pop() {
+            Some(flag @ (0x02 | 0x03)) => flag,
+            _ => return Err(Error::InvalidEncoding),
+        };
 
         let x = BaseField::<S>::deserialize_compressed(&mut &buf[..])?;
         let (y1, y2) =
-            SWAffine::<CurveConfig<S>>::get_ys_from_x_unchecked(x).ok_or(Error::InvalidData)?;
+            SWAffine::<CurveConfig<S>>::get_ys_from_x_unchecked(x).ok_or(Error::InvalidEncoding)?;
         let y = if ((y_flag & 0x01) != 0) == y1.into_bigint().is_odd() {
             y1
         } else {
             y2
         };
         let sw = SWAffine::<CurveConfig<S>>::new_unchecked(x, y);
-        Ok(AffinePoint::<S>::from_sw(sw))
+        AffinePoint::<S>::try_from_sw(sw).ok_or(Error::InvalidEncoding)
     }
 
     fn scalar_encode(sc: &ScalarField<S>, buf: &mut Vec<u8>) {
@@ -113,6 +120,140 @@ where
     }
 }
 
+/// RFC-8032 codec (https://datatracker.ietf.org/doc/rfc8032)
+///
+/// Little endian. Twisted Edwards points encoded as the `y` coordinate with the
+/// parity of the `x` coordinate in the MSB. Compression enabled.
+///
+/// Requires the base field modulus to leave the most significant bit of the
+/// encoded `y` coordinate unused (e.g. edwards25519).
+pub struct Rfc8032Codec;
+
+impl<S, C> Codec<S> for Rfc8032Codec
+where
+    S: Suite<Affine = ark_ec::twisted_edwards::Affine<C>>,
+    C: ark_ec::twisted_edwards::TECurveConfig,
+    C::BaseField: ark_ff::PrimeField,
+{
+    const BIG_ENDIAN: bool = false;
+
+    fn point_encode(pt: &AffinePoint<S>, buf: &mut Vec<u8>) {
+        use ark_ff::biginteger::BigInteger;
+
+        let mut tmp = Vec::new();
+        pt.y.serialize_compressed(&mut tmp).unwrap();
+        if pt.x.into_bigint().is_odd() {
+            let last = tmp.len() - 1;
+            tmp[last] |= 0x80;
+        }
+        buf.extend_from_slice(&tmp[..]);
+    }
+
+    fn point_decode(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+        use ark_ff::{biginteger::BigInteger, Zero};
+        type TEAffine<C> = ark_ec::twisted_edwards::Affine<C>;
+
+        let len = C::BaseField::zero().compressed_size();
+        if buf.len() != len {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut tmp = buf.to_vec();
+        let x_odd = tmp[len - 1] & 0x80 != 0;
+        tmp[len - 1] &= 0x7f;
+
+        // Fails if `y` is not canonically encoded.
+        let y = C::BaseField::deserialize_compressed(&tmp[..])?;
+        let (x1, x2) = TEAffine::<C>::get_xs_from_y_unchecked(y).ok_or(Error::InvalidEncoding)?;
+        let x = if x1.into_bigint().is_odd() == x_odd {
+            x1
+        } else {
+            x2
+        };
+        if x.is_zero() && x_odd {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(TEAffine::<C>::new_unchecked(x, y))
+    }
+
+    fn scalar_encode(sc: &ScalarField<S>, buf: &mut Vec<u8>) {
+        sc.serialize_compressed(buf).unwrap();
+    }
+
+    fn scalar_decode(buf: &[u8]) -> ScalarField<S> {
+        ScalarField::<S>::from_le_bytes_mod_order(buf)
+    }
+}
+
+/// Pasta curves codec, as implemented by the `pasta_curves` crate (Zcash, Halo2).
+///
+/// Little endian. Short Weierstrass points encoded as the `x` coordinate with the
+/// parity of the `y` coordinate in the MSB. The point at infinity is encoded as
+/// all zeros. Compression enabled.
+///
+/// Requires the base field modulus to leave the most significant bit of the
+/// encoded `x` coordinate unused (e.g. Pallas and Vesta).
+pub struct PastaCodec;
+
+impl<S, C> Codec<S> for PastaCodec
+where
+    S: Suite<Affine = ark_ec::short_weierstrass::Affine<C>>,
+    C: SWCurveConfig,
+    C::BaseField: ark_ff::PrimeField,
+{
+    const BIG_ENDIAN: bool = false;
+
+    fn point_encode(pt: &AffinePoint<S>, buf: &mut Vec<u8>) {
+        use ark_ff::{biginteger::BigInteger, Zero};
+
+        if pt.is_zero() {
+            let len = C::BaseField::zero().compressed_size();
+            buf.resize(buf.len() + len, 0);
+            return;
+        }
+        let mut tmp = Vec::new();
+        pt.x.serialize_compressed(&mut tmp).unwrap();
+        if pt.y.into_bigint().is_odd() {
+            let last = tmp.len() - 1;
+            tmp[last] |= 0x80;
+        }
+        buf.extend_from_slice(&tmp[..]);
+    }
+
+    fn point_decode(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+        use ark_ff::{biginteger::BigInteger, Zero};
+        type SWAffine<C> = ark_ec::short_weierstrass::Affine<C>;
+
+        let len = C::BaseField::zero().compressed_size();
+        if buf.len() != len {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut tmp = buf.to_vec();
+        let y_odd = tmp[len - 1] & 0x80 != 0;
+        tmp[len - 1] &= 0x7f;
+
+        // Fails if `x` is not canonically encoded.
+        let x = C::BaseField::deserialize_compressed(&tmp[..])?;
+        if x.is_zero() && !y_odd {
+            return Ok(SWAffine::<C>::identity());
+        }
+        let (y1, y2) = SWAffine::<C>::get_ys_from_x_unchecked(x).ok_or(Error::InvalidEncoding)?;
+        let y = if y1.into_bigint().is_odd() == y_odd {
+            y1
+        } else {
+            y2
+        };
+        Ok(SWAffine::<C>::new_unchecked(x, y))
+    }
+
+    fn scalar_encode(sc: &ScalarField<S>, buf: &mut Vec<u8>) {
+        sc.serialize_compressed(buf).unwrap();
+    }
+
+    fn scalar_decode(buf: &[u8]) -> ScalarField<S> {
+        ScalarField::<S>::from_le_bytes_mod_order(buf)
+    }
+}
+
 /// Point encoder wrapper using `Suite::Codec`.
 pub fn point_encode<S: Suite>(pt: &AffinePoint<S>) -> Vec<u8> {
     let mut buf = Vec::new();
@@ -120,11 +261,29 @@ pub fn point_encode<S: Suite>(pt: &AffinePoint<S>) -> Vec<u8> {
     buf
 }
 
+/// Length of a point encoded via `Suite::Codec`.
+pub fn point_encoded_len<S: Suite>() -> usize {
+    point_encode::<S>(&S::generator()).len()
+}
+
 /// Point decoder wrapper using `Suite::Codec`.
 pub fn point_decode<S: Suite>(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
     S::Codec::point_decode(buf)
 }
 
+/// Strict point decoder using `Suite::Codec`.
+///
+/// On top of `point_decode`, requires `buf` to be the canonical encoding of
+/// the decoded point and the point to be in the prime order subgroup.
+pub fn point_decode_strict<S: Suite>(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+    use ark_std::Zero;
+    let pt = S::Codec::point_decode(buf)?;
+    if point_encode::<S>(&pt) != buf || !pt.mul_bigint(ScalarField::<S>::MODULUS).is_zero() {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(pt)
+}
+
 /// Scalar encoder wrapper using `Suite::Codec`.
 pub fn scalar_encode<S: Suite>(sc: &ScalarField<S>) -> Vec<u8> {
     let mut buf = Vec::new();
@@ -137,6 +296,18 @@ pub fn scalar_decode<S: Suite>(buf: &[u8]) -> ScalarField<S> {
     S::Codec::scalar_decode(buf)
 }
 
+/// Strict scalar decoder using `Suite::Codec`.
+///
+/// Unlike `scalar_decode`, which reduces modulo the group order, requires
+/// `buf` to be the canonical encoding of the decoded scalar.
+pub fn scalar_decode_strict<S: Suite>(buf: &[u8]) -> Result<ScalarField<S>, Error> {
+    let sc = S::Codec::scalar_decode(buf);
+    if scalar_encode::<S>(&sc) != buf {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(sc)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::suites::testing::{Public, Secret};
@@ -158,4 +329,21 @@ mod tests {
         let public2 = Public::deserialize_compressed(&mut &buf[..]).unwrap();
         assert_eq!(public, public2);
     }
+
+    #[cfg(feature = "secp256r1")]
+    #[test]
+    fn sec1_decode_rejects_malformed() {
+        use crate::suites::secp256::P256Sha256Tai as S;
+        use crate::{codec::Codec, Error};
+        type C = <S as crate::Suite>::Codec;
+
+        assert_eq!(
+            <C as Codec<S>>::point_decode(&[]),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            <C as Codec<S>>::point_decode(&[0x04; 33]),
+            Err(Error::InvalidEncoding)
+        );
+    }
 }
@@ -0,0 +1,108 @@
+//! Hierarchical deterministic key derivation.
+//!
+//! Child keys are derived from a parent key following a path of junctions.
+//!
+//! - Soft junction: `t = Hash(suite_string || 0xD5 || point_to_string(Y) || id || 0x00)`
+//!   interpreted as a little endian integer modulo the group order. The child
+//!   secret is `x + t` and the child public key is `Y + t*G`. Thus soft derivation
+//!   can be performed on the public key alone.
+//!
+//! - Hard junction: the child secret is constructed via `Secret::from_seed` with
+//!   seed `suite_string || 0xD6 || int_to_string(x) || id || 0x00`. Requires the
+//!   parent secret key.
+//!
+//! Here `x` and `Y` are the parent secret and public keys, `G` is the suite generator.
+//! Encodings are as per `Suite::Codec`.
+
+use crate::*;
+
+const DOM_SEP_SOFT: u8 = 0xD5;
+const DOM_SEP_HARD: u8 = 0xD6;
+const DOM_SEP_END: u8 = 0x00;
+
+/// Key derivation path junction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeriveJunction<'a> {
+    /// Soft derivation, with the given identifier.
+    Soft(&'a [u8]),
+    /// Hard derivation, with the given identifier.
+    Hard(&'a [u8]),
+}
+
+fn soft_tweak<S: Suite>(public: &Public<S>, id: &[u8]) -> ScalarField<S> {
+    let mut buf = [S::SUITE_ID, &[DOM_SEP_SOFT]].concat();
+    S::Codec::point_encode(&public.0, &mut buf);
+    buf.extend_from_slice(id);
+    buf.push(DOM_SEP_END);
+    let hash = utils::hash::<S::Hasher>(&buf);
+    S::scalar_from_le_bytes_mod_order(&hash[..])
+}
+
+impl<S: Suite> Secret<S> {
+    /// Derive a child secret key following the given `path`.
+    pub fn derive(&self, path: &[DeriveJunction]) -> Self {
+        let mut secret = self.clone();
+        for junction in path {
+            secret = match junction {
+                DeriveJunction::Soft(id) => {
+                    Self::from_scalar(secret.scalar + soft_tweak(&secret.public, id))
+                }
+                DeriveJunction::Hard(id) => {
+                    let mut seed = [S::SUITE_ID, &[DOM_SEP_HARD]].concat();
+                    S::Codec::scalar_encode(&secret.scalar, &mut seed);
+                    seed.extend_from_slice(id);
+                    seed.push(DOM_SEP_END);
+                    let child = Self::from_seed(&seed);
+                    seed.as_mut_slice().zeroize();
+                    child
+                }
+            };
+        }
+        secret
+    }
+}
+
+impl<S: Suite> Public<S> {
+    /// Derive a child public key following the given `path`.
+    ///
+    /// Returns `Error::InvalidData` if `path` contains a hard junction.
+    pub fn derive(&self, path: &[DeriveJunction]) -> Result<Self, Error> {
+        path.iter().try_fold(*self, |public, junction| match junction {
+            DeriveJunction::Soft(id) => {
+                let tweak = S::generator() * soft_tweak(&public, id);
+                Ok(Public((tweak + public.0).into_affine()))
+            }
+            DeriveJunction::Hard(_) => Err(Error::InvalidData),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeriveJunction::{Hard, Soft};
+    use crate::suites::testing::Secret;
+    use crate::testing::TEST_SEED;
+
+    #[test]
+    fn derive_works() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+
+        assert_eq!(secret.derive(&[]), secret);
+
+        let path = [Soft(b"foo"), Soft(b"bar")];
+        let child = secret.derive(&path);
+        assert_ne!(child, secret);
+        assert_eq!(public.derive(&path).unwrap(), child.public());
+        assert_ne!(secret.derive(&[Soft(b"foo")]), child);
+
+        let path = [Soft(b"foo"), Hard(b"bar")];
+        let child = secret.derive(&path);
+        assert_ne!(child, secret.derive(&[Soft(b"foo"), Soft(b"bar")]));
+        assert_ne!(child, secret.derive(&[Hard(b"foo"), Hard(b"bar")]));
+        assert!(public.derive(&path).is_err());
+
+        // Derivation is deterministic.
+        assert_eq!(secret.derive(&path), child);
+    }
+}
@@ -0,0 +1,186 @@
+//! Self describing, suite tagged, encoding of VRF artifacts.
+//!
+//! Allows to store objects (e.g. signatures and proofs) produced by different
+//! suites side by side and to decode them without knowing the suite in advance.
+//!
+//! Envelope format:
+//! `version (1) || suite_id_len (1) || suite_id || payload`
+//!
+//! The payload is serialized using arkworks compressed format.
+
+use crate::*;
+
+/// Envelope format version.
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Serialize a suite artifact within a suite tagged envelope.
+pub fn encode<S: Suite, T: CanonicalSerialize>(payload: &T) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(2 + S::SUITE_ID.len() + payload.compressed_size());
+    buf.push(ENVELOPE_VERSION);
+    buf.push(utils::LenPrefix::<S>::SUITE_ID);
+    buf.extend_from_slice(S::SUITE_ID);
+    payload
+        .serialize_compressed(&mut buf)
+        .expect("serialization to vec can't fail");
+    buf
+}
+
+/// Deserialize an artifact previously serialized with `encode`.
+///
+/// Returns `Error::IncompatibleFormat` if the envelope version or suite
+/// identifier doesn't match, and `Error::InvalidEncoding` if the payload itself
+/// is not valid.
+pub fn decode<S: Suite, T: CanonicalDeserialize>(buf: &[u8]) -> Result<T, Error> {
+    let (suite_id, mut payload) = split(buf)?;
+    if suite_id != S::SUITE_ID {
+        return Err(Error::IncompatibleFormat);
+    }
+    T::deserialize_compressed(&mut payload).map_err(Into::into)
+}
+
+/// Suite identifier of an envelope.
+pub fn suite_id(buf: &[u8]) -> Result<&[u8], Error> {
+    split(buf).map(|(suite_id, _)| suite_id)
+}
+
+/// Suite specific envelope payload handler.
+///
+/// Used by `decode_any` to hand the payload to the code generic over the
+/// suite found in the envelope.
+#[cfg(feature = "pedersen")]
+pub trait SuiteVisitor {
+    /// Value produced by the visitor.
+    type Value;
+
+    /// Process the payload of an envelope tagged with `S` suite identifier.
+    fn visit<S: pedersen::PedersenSuite>(self, payload: &[u8]) -> Result<Self::Value, Error>;
+}
+
+/// Decode an envelope dispatching its payload to the matching suite.
+///
+/// Only suites enabled via crate features are recognized. Returns
+/// `Error::IncompatibleFormat` if the envelope version or suite is unknown.
+#[cfg(feature = "pedersen")]
+pub fn decode_any<V: SuiteVisitor>(buf: &[u8], visitor: V) -> Result<V::Value, Error> {
+    let (suite_id, payload) = split(buf)?;
+
+    macro_rules! dispatch {
+        ($($feature:meta => $suite:ty),* $(,)?) => {
+            $(
+                #[cfg($feature)]
+                if suite_id == <$suite as Suite>::SUITE_ID {
+                    return visitor.visit::<$suite>(payload);
+                }
+            )*
+        };
+    }
+
+    dispatch!(
+        feature = "ed25519" => suites::ed25519::Ed25519Sha512Tai,
+        feature = "ed25519" => suites::ed25519_rfc_9381::Ed25519Sha512TaiRfc9381,
+        feature = "ed25519" => suites::ed25519_ell2::Ed25519Sha512Ell2,
+        feature = "ed25519" => suites::ristretto255::Ristretto255Sha512,
+        feature = "secp256r1" => suites::secp256::P256Sha256Tai,
+        feature = "secp256r1" => suites::secp256_sswu::P256Sha256Sswu,
+        feature = "secp384r1" => suites::secp384::P384Sha384Tai,
+        feature = "secp256k1" => suites::secp256k1::Secp256k1Sha256Tai,
+        feature = "keccak" => suites::secp256k1_keccak::Secp256k1Keccak256Tai,
+        feature = "bandersnatch" => suites::bandersnatch::weierstrass::BandersnatchSha512Tai,
+        feature = "bandersnatch" => suites::bandersnatch::edwards::BandersnatchSha512Ell2,
+        feature = "poseidon" => suites::bandersnatch::poseidon::BandersnatchPoseidon,
+        feature = "jubjub" => suites::jubjub::JubjubSha512Tai,
+        feature = "baby-jubjub" => suites::baby_jubjub::BabyJubjubKeccak256Tai,
+        all(feature = "baby-jubjub", feature = "poseidon") => suites::baby_jubjub::poseidon::BabyJubjubPoseidon,
+        feature = "pasta" => suites::pasta::pallas::PallasBlake2b512Tai,
+        feature = "pasta" => suites::pasta::vesta::VestaBlake2b512Tai,
+        feature = "bls12-381" => suites::bls12_381::Bls12_381G1Sha512Tai,
+    );
+
+    let _ = (visitor, payload);
+    Err(Error::IncompatibleFormat)
+}
+
+// Splits the envelope into suite identifier and payload.
+fn split(buf: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (version, buf) = split_at_checked(buf, 1)?;
+    if version[0] != ENVELOPE_VERSION {
+        return Err(Error::IncompatibleFormat);
+    }
+    let (suite_id_len, buf) = split_at_checked(buf, 1)?;
+    split_at_checked(buf, suite_id_len[0] as usize)
+}
+
+pub(crate) fn split_at_checked(buf: &[u8], mid: usize) -> Result<(&[u8], &[u8]), Error> {
+    match buf.len() >= mid {
+        true => Ok(buf.split_at(mid)),
+        false => Err(Error::IncompatibleFormat),
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod tests {
+    use super::*;
+    use crate::pedersen::PedersenSuite;
+    use crate::suites::testing::{Secret, TestSuite};
+    use crate::testing::TEST_SEED;
+
+    #[test]
+    fn envelope_roundtrip() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let input = Input::from(crate::testing::random_val(None));
+        let signature = secret.sign(input, b"foo");
+
+        let buf = encode::<TestSuite, _>(&signature);
+        assert_eq!(suite_id(&buf).unwrap(), TestSuite::SUITE_ID);
+        let decoded = decode::<TestSuite, pedersen::Signature<TestSuite>>(&buf).unwrap();
+        assert!(decoded.verify(input, b"foo").is_ok());
+
+        // Version, suite id length and suite id mismatches.
+        for i in [0, 1, 2] {
+            let mut bad = buf.clone();
+            bad[i] ^= 0x01;
+            let res = decode::<TestSuite, pedersen::Signature<TestSuite>>(&bad);
+            assert!(matches!(res, Err(Error::IncompatibleFormat)));
+        }
+
+        // Truncated payload.
+        let res = decode::<TestSuite, pedersen::Signature<TestSuite>>(&buf[..buf.len() - 1]);
+        assert!(matches!(res, Err(Error::InvalidEncoding)));
+
+        // Test suite is not dispatched.
+        struct Nop;
+        impl SuiteVisitor for Nop {
+            type Value = ();
+            fn visit<S: PedersenSuite>(self, _payload: &[u8]) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+        assert!(matches!(decode_any(&buf, Nop), Err(Error::IncompatibleFormat)));
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn envelope_decode_any() {
+        use crate::suites::ed25519::{Ed25519Sha512Tai, Input, Secret};
+
+        // Verifies a signature for whatever suite is found in the envelope.
+        struct VerifySignature<'a>(&'a [u8], &'a [u8]);
+        impl SuiteVisitor for VerifySignature<'_> {
+            type Value = Vec<u8>;
+            fn visit<S: PedersenSuite>(self, mut payload: &[u8]) -> Result<Vec<u8>, Error> {
+                let signature = pedersen::Signature::<S>::deserialize_compressed(&mut payload)?;
+                let input = crate::Input::<S>::new(self.0).ok_or(Error::InvalidData)?;
+                Ok(signature.verify(input, self.1)?.to_vec())
+            }
+        }
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let input = Input::new(b"alpha").unwrap();
+        let signature = secret.sign(input, b"foo");
+        let buf = encode::<Ed25519Sha512Tai, _>(&signature);
+
+        let hash = decode_any(&buf, VerifySignature(b"alpha", b"foo")).unwrap();
+        assert_eq!(hash, signature.output_hash().to_vec());
+        assert!(decode_any(&buf, VerifySignature(b"alpha", b"bar")).is_err());
+    }
+}
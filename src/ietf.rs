@@ -14,12 +14,95 @@ impl<T> IetfSuite for T where T: Suite {}
 ///
 /// An output point which can be used to derive the actual output together
 /// with the actual proof of the input point and the associated data.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Proof<S: IetfSuite> {
     pub c: ScalarField<S>,
     pub s: ScalarField<S>,
 }
 
+// Hex encoding is the `CanonicalSerialize` one: `c || s`.
+utils::bytes_encoding_impl!(
+    Proof<S: IetfSuite>,
+    |proof: &Proof<S>| {
+        let mut buf = Vec::new();
+        proof
+            .serialize_compressed(&mut buf)
+            .expect("serialization to vec can't fail");
+        buf
+    },
+    |mut buf: &[u8]| -> Result<Proof<S>, Error> {
+        let proof = Proof::deserialize_compressed(&mut buf)?;
+        match buf.is_empty() {
+            true => Ok(proof),
+            false => Err(Error::InvalidEncoding),
+        }
+    },
+    || S::CHALLENGE_LEN + ScalarField::<S>::default().compressed_size()
+);
+
+impl<S: IetfSuite> Proof<S> {
+    /// Length of the RFC-9381 `pi_string` (`ptLen + cLen + qLen`).
+    pub fn pi_string_len() -> usize {
+        let point_len = codec::point_encode::<S>(&S::generator()).len();
+        let scalar_len = codec::scalar_encode::<S>(&ScalarField::<S>::from(1)).len();
+        point_len + S::CHALLENGE_LEN + scalar_len
+    }
+
+    /// Encode as RFC-9381 `pi_string`: `point_to_string(gamma) || c || s`.
+    ///
+    /// The challenge is encoded on `cLen` bytes, the other elements as per
+    /// `Suite::Codec`.
+    pub fn to_pi_string(&self, output: &Output<S>) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::pi_string_len());
+        S::Codec::point_encode(&output.0, &mut buf);
+        let c_buf = codec::scalar_encode::<S>(&self.c);
+        if S::Codec::BIG_ENDIAN {
+            buf.extend_from_slice(&c_buf[c_buf.len() - S::CHALLENGE_LEN..]);
+        } else {
+            buf.extend_from_slice(&c_buf[..S::CHALLENGE_LEN]);
+        }
+        S::Codec::scalar_encode(&self.s, &mut buf);
+        buf
+    }
+
+    /// Decode a RFC-9381 `pi_string` into the VRF output (gamma) and the proof.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` length is not `pi_string_len()`, if
+    /// gamma is not a valid point in the prime order subgroup or if any of the
+    /// elements is not canonically encoded (e.g. scalars not less than the group
+    /// order).
+    pub fn from_pi_string(buf: &[u8]) -> Result<(Output<S>, Self), Error> {
+        use ark_std::Zero;
+        let (output, proof) = Self::from_pi_string_unchecked(buf)?;
+        if !output.0.mul_bigint(ScalarField::<S>::MODULUS).is_zero()
+            || proof.to_pi_string(&output) != buf
+        {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok((output, proof))
+    }
+
+    /// Decode a RFC-9381 `pi_string` without canonicity and subgroup checks.
+    ///
+    /// Scalars are reduced modulo the group order. Only the length of `buf`
+    /// and the point decoding are checked. Use `from_pi_string` unless
+    /// interoperating with encoders known to be non canonical.
+    pub fn from_pi_string_unchecked(buf: &[u8]) -> Result<(Output<S>, Self), Error> {
+        if buf.len() != Self::pi_string_len() {
+            return Err(Error::InvalidEncoding);
+        }
+        let scalar_len = codec::scalar_encode::<S>(&ScalarField::<S>::from(1)).len();
+        let (gamma_buf, rest) = buf.split_at(buf.len() - S::CHALLENGE_LEN - scalar_len);
+        let (c_buf, s_buf) = rest.split_at(S::CHALLENGE_LEN);
+
+        let gamma = codec::point_decode::<S>(gamma_buf)?;
+        let c = codec::scalar_decode::<S>(c_buf);
+        let s = codec::scalar_decode::<S>(s_buf);
+
+        Ok((Output(gamma), Proof { c, s }))
+    }
+}
+
 impl<S: IetfSuite> CanonicalSerialize for Proof<S> {
     fn serialize_with_mode<W: ark_serialize::Write>(
         &self,
@@ -57,6 +140,19 @@ impl<S: IetfSuite> CanonicalDeserialize for Proof<S> {
             return Err(ark_serialize::SerializationError::InvalidData);
         }
         let c = codec::scalar_decode::<S>(&c_buf);
+        if validate == ark_serialize::Validate::Yes {
+            // Reject challenges not canonically encoded (i.e. not less than the
+            // group order once decoded).
+            let enc = codec::scalar_encode::<S>(&c);
+            let enc = if S::Codec::BIG_ENDIAN {
+                &enc[enc.len().saturating_sub(S::CHALLENGE_LEN)..]
+            } else {
+                &enc[..S::CHALLENGE_LEN.min(enc.len())]
+            };
+            if enc != &c_buf[..] {
+                return Err(ark_serialize::SerializationError::InvalidData);
+            }
+        }
         let s = <ScalarField<S> as CanonicalDeserialize>::deserialize_with_mode(
             &mut reader,
             ark_serialize::Compress::No,
@@ -77,6 +173,39 @@ impl<S: IetfSuite> ark_serialize::Valid for Proof<S> {
 pub trait Prover<S: IetfSuite> {
     /// Generate a proof for the given input/output and user additional data.
     fn prove(&self, input: Input<S>, output: Output<S>, ad: impl AsRef<[u8]>) -> Proof<S>;
+
+    /// Generate a proof and verify it against the prover public key before
+    /// returning it.
+    ///
+    /// Fault attack countermeasure: a glitch during the deterministic proving
+    /// procedure may produce a faulty proof leaking the secret key.
+    ///
+    /// Returns `Error::VerificationFailure` if the proof is not valid, in which
+    /// case nothing should be disclosed.
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<Proof<S>, Error>;
+
+    /// Generate a single proof for multiple input/output pairs.
+    ///
+    /// The pairs are merged via a random linear combination, with weights
+    /// derived by hashing all the pairs, and the merged pair is then proven.
+    /// The proof size is thus independent of the number of pairs.
+    ///
+    /// Returns `Error::InvalidData` if `inputs` is empty or if its length
+    /// doesn't match `outputs` length.
+    fn prove_many(
+        &self,
+        inputs: &[Input<S>],
+        outputs: &[Output<S>],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<Proof<S>, Error> {
+        let (input, output) = merge_ios(inputs, outputs)?;
+        Ok(self.prove(input, output, ad))
+    }
 }
 
 pub trait Verifier<S: IetfSuite> {
@@ -88,6 +217,18 @@ pub trait Verifier<S: IetfSuite> {
         ad: impl AsRef<[u8]>,
         sig: &Proof<S>,
     ) -> Result<(), Error>;
+
+    /// Verify a proof generated via `Prover::prove_many`.
+    fn verify_many(
+        &self,
+        inputs: &[Input<S>],
+        outputs: &[Output<S>],
+        ad: impl AsRef<[u8]>,
+        sig: &Proof<S>,
+    ) -> Result<(), Error> {
+        let (input, output) = merge_ios(inputs, outputs)?;
+        self.verify(input, output, ad, sig)
+    }
 }
 
 impl<S: IetfSuite> Prover<S> for Secret<S> {
@@ -104,6 +245,17 @@ impl<S: IetfSuite> Prover<S> for Secret<S> {
         let s = k + c * self.scalar;
         Proof { c, s }
     }
+
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<Proof<S>, Error> {
+        let proof = Prover::prove(self, input, output, ad.as_ref());
+        Verifier::verify(&self.public, input, output, ad, &proof)?;
+        Ok(proof)
+    }
 }
 
 impl<S: IetfSuite> Verifier<S> for Public<S> {
@@ -120,14 +272,159 @@ impl<S: IetfSuite> Verifier<S> for Public<S> {
         let c_y = self.0 * c;
         let u = (s_b - c_y).into_affine();
 
-        let s_h = input.0 * s;
-        let c_o = output.0 * c;
-        let v = (s_h - c_o).into_affine();
+        verify_challenge(&self.0, u, input, output, ad.as_ref(), proof)
+    }
+}
+
+// Completes the verification given `u = s*G - c*Y`.
+fn verify_challenge<S: IetfSuite>(
+    public: &AffinePoint<S>,
+    u: AffinePoint<S>,
+    input: Input<S>,
+    output: Output<S>,
+    ad: &[u8],
+    proof: &Proof<S>,
+) -> Result<(), Error> {
+    let Proof { c, s } = proof;
+
+    let s_h = input.0 * s;
+    let c_o = output.0 * c;
+    let v = (s_h - c_o).into_affine();
+
+    let c_exp = S::challenge(&[public, &input.0, &output.0, &u, &v], ad);
+    (&c_exp == c)
+        .then_some(())
+        .ok_or(Error::VerificationFailure)
+}
+
+type Group<S> = <AffinePoint<S> as AffineRepr>::Group;
+
+/// Merge input/output pairs using a random linear combination.
+///
+/// Weights are derived by hashing all the pairs, thus the merged pair can't
+/// be chosen independently of the single pairs.
+pub(crate) fn merge_ios<S: Suite>(
+    inputs: &[Input<S>],
+    outputs: &[Output<S>],
+) -> Result<(Input<S>, Output<S>), Error> {
+    use ark_ec::VariableBaseMSM;
+
+    const DOM_SEP: u8 = 0xD1;
+
+    if inputs.is_empty() || inputs.len() != outputs.len() {
+        return Err(Error::InvalidData);
+    }
+    let mut buf = [S::SUITE_ID, &[DOM_SEP]].concat();
+    buf.extend_from_slice(&(inputs.len() as u64).to_le_bytes());
+    for (input, output) in inputs.iter().zip(outputs) {
+        S::Codec::point_encode(&input.0, &mut buf);
+        S::Codec::point_encode(&output.0, &mut buf);
+    }
+    let seed = utils::hash::<S::Hasher>(&buf);
+    let weights: Vec<_> = (0..inputs.len() as u64)
+        .map(|i| {
+            let hash = utils::hash::<S::Hasher>(&[&seed[..], &i.to_le_bytes()].concat());
+            S::scalar_from_le_bytes_mod_order(&hash[..16])
+        })
+        .collect();
+
+    let inputs: Vec<_> = inputs.iter().map(|i| i.0).collect();
+    let outputs: Vec<_> = outputs.iter().map(|o| o.0).collect();
+    let input = Group::<S>::msm(&inputs, &weights).map_err(|_| Error::InvalidData)?;
+    let output = Group::<S>::msm(&outputs, &weights).map_err(|_| Error::InvalidData)?;
+    Ok((Input(input.into_affine()), Output(output.into_affine())))
+}
+
+/// Fixed base multiplication table.
+///
+/// For each window `i` holds `j * 2^(WINDOW * i) * P`, for `j` in `[0, 2^WINDOW)`.
+/// Multiplication is not constant time, thus usable with public scalars only.
+///
+/// Shared by all the verifiers precomputations (e.g. `PedersenVerifierPrecomp`).
+#[derive(Clone)]
+pub(crate) struct FixedBaseTable<S: Suite>(Vec<Vec<AffinePoint<S>>>);
+
+impl<S: Suite> FixedBaseTable<S> {
+    const WINDOW: usize = 4;
+
+    pub(crate) fn new(base: AffinePoint<S>) -> Self {
+        use ark_std::Zero;
+        let bits = ScalarField::<S>::MODULUS_BIT_SIZE as usize;
+        let mut base = base.into_group();
+        let table = (0..bits.div_ceil(Self::WINDOW))
+            .map(|_| {
+                let mut row = Vec::with_capacity(1 << Self::WINDOW);
+                let mut acc = Group::<S>::zero();
+                for _ in 0..(1 << Self::WINDOW) {
+                    row.push(acc);
+                    acc += base;
+                }
+                base = acc;
+                Group::<S>::normalize_batch(&row)
+            })
+            .collect();
+        Self(table)
+    }
 
-        let c_exp = S::challenge(&[&self.0, &input.0, &output.0, &u, &v], ad.as_ref());
-        (&c_exp == c)
-            .then_some(())
-            .ok_or(Error::VerificationFailure)
+    pub(crate) fn mul(&self, scalar: &ScalarField<S>) -> Group<S> {
+        use ark_ff::BigInteger;
+        use ark_std::Zero;
+        let scalar = scalar.into_bigint();
+        self.0
+            .iter()
+            .enumerate()
+            .fold(Group::<S>::zero(), |acc, (i, row)| {
+                let digit = (0..Self::WINDOW).fold(0, |digit, j| {
+                    digit | ((scalar.get_bit(i * Self::WINDOW + j) as usize) << j)
+                });
+                acc + row[digit]
+            })
+    }
+}
+
+/// Public key with precomputed fixed base multiplication tables.
+///
+/// Amortizes the fixed base work (public key and generator multiplications)
+/// when verifying many proofs for the same public key.
+/// Constructed via `Public::precompute`.
+#[derive(Clone)]
+pub struct PrecomputedPublic<S: IetfSuite> {
+    public: Public<S>,
+    generator_table: FixedBaseTable<S>,
+    public_table: FixedBaseTable<S>,
+}
+
+impl<S: IetfSuite> PrecomputedPublic<S> {
+    /// Get the public key.
+    pub fn public(&self) -> Public<S> {
+        self.public
+    }
+}
+
+impl<S: IetfSuite> Public<S> {
+    /// Precompute the fixed base tables used to speed up verification.
+    pub fn precompute(&self) -> PrecomputedPublic<S> {
+        PrecomputedPublic {
+            public: *self,
+            generator_table: FixedBaseTable::new(S::generator()),
+            public_table: FixedBaseTable::new(self.0),
+        }
+    }
+}
+
+impl<S: IetfSuite> Verifier<S> for PrecomputedPublic<S> {
+    fn verify(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        let s_b = self.generator_table.mul(&proof.s);
+        let c_y = self.public_table.mul(&proof.c);
+        let u = (s_b - c_y).into_affine();
+
+        verify_challenge(&self.public.0, u, input, output, ad.as_ref(), proof)
     }
 }
 
@@ -147,6 +444,81 @@ pub mod testing {
         let proof = secret.prove(input, output, b"foo");
         let result = public.verify(input, output, b"foo", &proof);
         assert!(result.is_ok());
+
+        let checked = secret.prove_checked(input, output, b"foo").unwrap();
+        assert_eq!((checked.c, checked.s), (proof.c, proof.s));
+        let other = Secret::<S>::from_seed(b"other").output(input);
+        assert_eq!(
+            secret.prove_checked(input, other, b"foo").unwrap_err(),
+            Error::VerificationFailure
+        );
+    }
+
+    pub fn prove_verify_many<S: IetfSuite>() {
+        use ietf::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+        let inputs: Vec<_> = (0..3)
+            .map(|_| Input::<S>::from(common::random_val(None)))
+            .collect();
+        let mut outputs: Vec<_> = inputs.iter().map(|i| secret.output(*i)).collect();
+
+        let proof = secret.prove_many(&inputs, &outputs, b"foo").unwrap();
+        assert!(public.verify_many(&inputs, &outputs, b"foo", &proof).is_ok());
+        assert!(public.verify_many(&inputs, &outputs, b"bar", &proof).is_err());
+
+        outputs.swap(0, 1);
+        assert!(public.verify_many(&inputs, &outputs, b"foo", &proof).is_err());
+
+        assert!(secret.prove_many(&[], &[], b"foo").is_err());
+        assert!(secret.prove_many(&inputs, &outputs[1..], b"foo").is_err());
+    }
+
+    pub fn pi_string<S: IetfSuite>() {
+        use ietf::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let input = Input::from(common::random_val(None));
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, b"foo");
+
+        let buf = proof.to_pi_string(&output);
+        assert_eq!(buf.len(), Proof::<S>::pi_string_len());
+        let (output2, proof2) = Proof::<S>::from_pi_string(&buf).unwrap();
+        assert_eq!(output, output2);
+        assert!(secret
+            .public()
+            .verify(input, output2, b"foo", &proof2)
+            .is_ok());
+
+        // Wrong length
+        assert!(Proof::<S>::from_pi_string(&buf[1..]).is_err());
+        assert!(Proof::<S>::from_pi_string(&[&buf[..], &[0]].concat()).is_err());
+
+        // Non canonical `s`
+        let mut bad = buf.clone();
+        let len = bad.len();
+        let scalar_len = codec::scalar_encode::<S>(&proof.s).len();
+        bad[len - scalar_len..].fill(0xff);
+        assert!(Proof::<S>::from_pi_string(&bad).is_err());
+        assert!(Proof::<S>::from_pi_string_unchecked(&bad).is_ok());
+    }
+
+    pub fn precomputed_verify<S: IetfSuite>() {
+        use ietf::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public().precompute();
+        let input = Input::from(common::random_val(None));
+        let output = secret.output(input);
+
+        let proof = secret.prove(input, output, b"foo");
+        assert!(public.verify(input, output, b"foo", &proof).is_ok());
+        assert!(public.verify(input, output, b"bar", &proof).is_err());
+
+        let other = Secret::<S>::from_seed(b"other").public().precompute();
+        assert!(other.verify(input, output, b"foo", &proof).is_err());
     }
 
     #[macro_export]
@@ -156,6 +528,21 @@ pub mod testing {
             fn ietf_prove_verify() {
                 $crate::ietf::testing::prove_verify::<$suite>();
             }
+
+            #[test]
+            fn ietf_prove_verify_many() {
+                $crate::ietf::testing::prove_verify_many::<$suite>();
+            }
+
+            #[test]
+            fn ietf_pi_string() {
+                $crate::ietf::testing::pi_string::<$suite>();
+            }
+
+            #[test]
+            fn ietf_precomputed_verify() {
+                $crate::ietf::testing::precomputed_verify::<$suite>();
+            }
         };
     }
 
@@ -0,0 +1,273 @@
+//! Passphrase encrypted secret key storage.
+//!
+//! The secret scalar is encrypted with XChaCha20-Poly1305 under a key derived
+//! from the passphrase via scrypt. The suite identifier and the public key are
+//! authenticated as associated data.
+//!
+//! Versioned JSON format:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "suite": "<hex suite_string>",
+//!   "public": "<hex point_to_string(Y)>",
+//!   "kdf": { "name": "scrypt", "log_n": 15, "r": 8, "p": 1, "salt": "<hex>" },
+//!   "cipher": { "name": "xchacha20poly1305", "nonce": "<hex>" },
+//!   "ciphertext": "<hex>"
+//! }
+//! ```
+
+use crate::*;
+use ark_std::string::{String, ToString};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+
+/// Keystore format version.
+pub const KEYSTORE_VERSION: u32 = 1;
+
+const KDF_NAME: &str = "scrypt";
+const CIPHER_NAME: &str = "xchacha20poly1305";
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// Maximum accepted `KdfParams::log_n` (1 GiB of memory with `r = 8`).
+pub const MAX_LOG_N: u8 = 20;
+/// Maximum accepted `KdfParams::r * KdfParams::p`.
+pub const MAX_R_P: u64 = 1 << 10;
+
+/// Scrypt key derivation parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Log2 of the CPU/memory cost parameter.
+    pub log_n: u8,
+    /// Block size parameter.
+    pub r: u32,
+    /// Parallelization parameter.
+    pub p: u32,
+}
+
+impl KdfParams {
+    /// Check the parameters against the accepted ceilings.
+    ///
+    /// Keystores are untrusted input, thus the derivation cost must be bounded
+    /// before running it.
+    fn check(&self) -> Result<(), Error> {
+        if self.log_n > MAX_LOG_N || self.r as u64 * self.p as u64 > MAX_R_P {
+            return Err(Error::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KdfJson {
+    name: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherJson {
+    name: String,
+    nonce: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreJson {
+    version: u32,
+    suite: String,
+    public: String,
+    kdf: KdfJson,
+    cipher: CipherJson,
+    ciphertext: String,
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8], params: &KdfParams) -> Result<[u8; KEY_LEN], Error> {
+    params.check()?;
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_LEN)
+        .map_err(|_| Error::InvalidData)?;
+    let mut key = [0; KEY_LEN];
+    scrypt::scrypt(passphrase, salt, &params, &mut key).map_err(|_| Error::InvalidData)?;
+    Ok(key)
+}
+
+fn aad<S: Suite>(public: &Public<S>) -> Vec<u8> {
+    let mut buf = S::SUITE_ID.to_vec();
+    S::Codec::point_encode(&public.0, &mut buf);
+    buf
+}
+
+/// Encrypt `secret` under `passphrase` and export it as keystore JSON.
+///
+/// Returns `Error::InvalidData` if the KDF parameters are not valid or exceed
+/// `MAX_LOG_N` or `MAX_R_P`.
+pub fn encrypt<S: Suite>(
+    secret: &Secret<S>,
+    passphrase: &str,
+    params: KdfParams,
+    rng: &mut (impl ark_std::rand::CryptoRng + ark_std::rand::RngCore),
+) -> Result<String, Error> {
+    let mut salt = [0; SALT_LEN];
+    rng.fill_bytes(&mut salt);
+    let mut nonce = [0; NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    let public = secret.public();
+    let mut key = derive_key(passphrase.as_bytes(), &salt, &params)?;
+    let mut plaintext = codec::scalar_encode::<S>(&secret.scalar);
+    let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key)).encrypt(
+        XNonce::from_slice(&nonce),
+        Payload {
+            msg: &plaintext,
+            aad: &aad(&public),
+        },
+    );
+    key.zeroize();
+    plaintext.zeroize();
+    let ciphertext = ciphertext.map_err(|_| Error::InvalidData)?;
+
+    let json = KeystoreJson {
+        version: KEYSTORE_VERSION,
+        suite: hex::encode(S::SUITE_ID),
+        public: hex::encode(codec::point_encode::<S>(&public.0)),
+        kdf: KdfJson {
+            name: KDF_NAME.to_string(),
+            log_n: params.log_n,
+            r: params.r,
+            p: params.p,
+            salt: hex::encode(salt),
+        },
+        cipher: CipherJson {
+            name: CIPHER_NAME.to_string(),
+            nonce: hex::encode(nonce),
+        },
+        ciphertext: hex::encode(ciphertext),
+    };
+    serde_json::to_string_pretty(&json).map_err(|_| Error::InvalidData)
+}
+
+/// Decrypt a secret previously exported via `encrypt`.
+///
+/// Returns `Error::IncompatibleFormat` if the keystore version, suite or
+/// algorithms don't match, `Error::VerificationFailure` if the passphrase is
+/// wrong or the keystore has been tampered with, `Error::InvalidData` if the KDF
+/// parameters exceed `MAX_LOG_N` or `MAX_R_P` and `Error::InvalidEncoding` if the
+/// content is malformed.
+pub fn decrypt<S: Suite>(json: &str, passphrase: &str) -> Result<Secret<S>, Error> {
+    let json: KeystoreJson = serde_json::from_str(json).map_err(|_| Error::InvalidEncoding)?;
+    if json.version != KEYSTORE_VERSION
+        || utils::hex_decode(&json.suite)? != S::SUITE_ID
+        || json.kdf.name != KDF_NAME
+        || json.cipher.name != CIPHER_NAME
+    {
+        return Err(Error::IncompatibleFormat);
+    }
+    let public = Public::<S>::from_bytes(&utils::hex_decode(&json.public)?)?;
+    let nonce = utils::hex_decode(&json.cipher.nonce)?;
+    if nonce.len() != NONCE_LEN {
+        return Err(Error::InvalidEncoding);
+    }
+    let params = KdfParams {
+        log_n: json.kdf.log_n,
+        r: json.kdf.r,
+        p: json.kdf.p,
+    };
+
+    let salt = utils::hex_decode(&json.kdf.salt)?;
+    let mut key = derive_key(passphrase.as_bytes(), &salt, &params)?;
+    let plaintext = XChaCha20Poly1305::new(Key::from_slice(&key)).decrypt(
+        XNonce::from_slice(&nonce),
+        Payload {
+            msg: &utils::hex_decode(&json.ciphertext)?,
+            aad: &aad(&public),
+        },
+    );
+    key.zeroize();
+    let mut plaintext = plaintext.map_err(|_| Error::VerificationFailure)?;
+
+    let scalar = codec::scalar_decode_strict::<S>(&plaintext);
+    plaintext.zeroize();
+    let secret = Secret::from_scalar(scalar?);
+    if secret.public() != public {
+        return Err(Error::InvalidData);
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::testing::{Secret, TestSuite};
+    use crate::testing::TEST_SEED;
+    use ark_std::rand::SeedableRng;
+
+    const TEST_PARAMS: KdfParams = KdfParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn keystore_roundtrip() {
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+        let secret = Secret::from_seed(TEST_SEED);
+
+        let json = encrypt(&secret, "foo", TEST_PARAMS, &mut rng).unwrap();
+        assert_eq!(decrypt::<TestSuite>(&json, "foo").unwrap(), secret);
+
+        let res = decrypt::<TestSuite>(&json, "bar");
+        assert!(matches!(res, Err(Error::VerificationFailure)));
+
+        // Public key is authenticated.
+        let other = Secret::from_seed(b"other").public();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["public"] = hex::encode(codec::point_encode::<TestSuite>(&other.0)).into();
+        let res = decrypt::<TestSuite>(&value.to_string(), "foo");
+        assert!(matches!(res, Err(Error::VerificationFailure)));
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["version"] = 2.into();
+        let res = decrypt::<TestSuite>(&value.to_string(), "foo");
+        assert!(matches!(res, Err(Error::IncompatibleFormat)));
+
+        let res = decrypt::<TestSuite>("{}", "foo");
+        assert!(matches!(res, Err(Error::InvalidEncoding)));
+    }
+
+    #[test]
+    fn keystore_kdf_params_ceiling() {
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+        let secret = Secret::from_seed(TEST_SEED);
+        let json = encrypt(&secret, "foo", TEST_PARAMS, &mut rng).unwrap();
+
+        for (log_n, r, p) in [(MAX_LOG_N + 1, 8, 1), (4, 1 << 10, 2), (4, u32::MAX, u32::MAX)] {
+            let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            value["kdf"]["log_n"] = log_n.into();
+            value["kdf"]["r"] = r.into();
+            value["kdf"]["p"] = p.into();
+            let res = decrypt::<TestSuite>(&value.to_string(), "foo");
+            assert!(matches!(res, Err(Error::InvalidData)));
+        }
+
+        let params = KdfParams {
+            log_n: MAX_LOG_N + 1,
+            ..TEST_PARAMS
+        };
+        let res = encrypt(&secret, "foo", params, &mut rng);
+        assert!(matches!(res, Err(Error::InvalidData)));
+    }
+}
@@ -24,26 +24,77 @@ use ark_std::vec::Vec;
 use digest::Digest;
 use rustler::{Decoder, Encoder, NifResult, Term};
 
+pub mod additional_data;
 pub mod codec;
-pub mod ietf;
-pub mod pedersen;
+pub mod derive;
+pub mod envelope;
+pub mod rerandomize;
+pub mod rng;
 pub mod suites;
 pub mod utils;
 
+#[cfg(feature = "ietf")]
+pub mod ietf;
+
+#[cfg(feature = "pedersen")]
+pub mod pedersen;
+
+#[cfg(feature = "pedersen")]
+pub mod signer;
+
 #[cfg(feature = "ring")]
 pub mod ring;
 
+#[cfg(feature = "any-suite")]
+pub mod any;
+
+#[cfg(feature = "keystore")]
+pub mod keystore;
+
+#[cfg(feature = "pkcs8")]
+pub mod pkcs8;
+
 #[allow(unused)]
 mod arkworks;
 
 #[cfg(test)]
 mod testing;
 
+/// Commonly used types and traits.
+///
+/// Provers and verifiers traits are renamed after their proof flavor, as the
+/// flavors share the same method names. Concrete type aliases for the built-in
+/// suites are found in the suites modules (e.g. `suites::bandersnatch::edwards`).
 pub mod prelude {
     pub use ark_ec;
     pub use ark_ff;
     pub use ark_serialize;
     pub use ark_std;
+    #[cfg(feature = "subtle")]
+    pub use subtle;
+    #[cfg(feature = "secrecy")]
+    pub use secrecy;
+
+    pub use crate::{
+        codec::Codec, suites, AdditionalData, Error, Input, Keypair, Output, Public, Secret, Suite,
+    };
+
+    #[cfg(feature = "ietf")]
+    pub use crate::ietf::{IetfSuite, Prover as IetfProver, Verifier as IetfVerifier};
+
+    #[cfg(feature = "pedersen")]
+    pub use crate::pedersen::{
+        PedersenSuite, Prover as PedersenProver, Verifier as PedersenVerifier,
+    };
+    #[cfg(feature = "pedersen")]
+    pub use crate::VrfSigner;
+
+    #[cfg(feature = "ring")]
+    pub use crate::ring::{
+        LinkabilityTag, Prover as RingVrfProver, RingSuite, Verifier as RingVrfVerifier,
+    };
+    #[cfg(feature = "async")]
+    pub use crate::ring::AsyncProver as AsyncRingVrfProver;
 }
 
 pub type AffinePoint<S> = <S as Suite>::Affine;
@@ -54,19 +105,78 @@ pub type CurveConfig<S> = <AffinePoint<S> as AffineRepr>::Config;
 
 pub type HashOutput<S> = digest::Output<<S as Suite>::Hasher>;
 
+pub use additional_data::AdditionalData;
 pub use codec::Codec;
+#[cfg(feature = "pedersen")]
+pub use signer::VrfSigner;
 
-#[derive(Debug)]
+/// Crate error.
+///
+/// New variants may be added in the future, thus matches should include a
+/// wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// Verification error(s)
     VerificationFailure,
+    /// Ring proof verification failure (the Pedersen proof is valid)
+    RingProofFailure,
+    /// Linkability tag doesn't match the proof VRF output
+    LinkabilityTagMismatch,
     /// Bad input data
     InvalidData,
+    /// Malformed or not canonical encoding
+    InvalidEncoding,
+    /// Public key is the identity, not on the curve or not in the prime order subgroup
+    InvalidPublicKey,
+    /// Operation cancelled by the user
+    Cancelled,
+    /// Serialized data format (magic, version or suite) mismatch
+    IncompatibleFormat,
+    /// Artifact produced using PCS parameters over a different pairing
+    SrsMismatch,
+    /// Ring commitment doesn't match the expected one
+    RingCommitmentMismatch,
+    /// Ring size exceeds the ring context capacity (`expected` is the max ring size)
+    RingTooLarge { expected: usize, actual: usize },
+    /// PCS parameters too small for the required domain (sizes are number of G1 powers)
+    SrsTooSmall { expected: usize, actual: usize },
+    /// Key index out of the ring bounds (`expected` is the ring size)
+    KeyIndexOutOfBounds { expected: usize, actual: usize },
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::VerificationFailure => f.write_str("verification failure"),
+            Error::RingProofFailure => f.write_str("ring proof verification failure"),
+            Error::LinkabilityTagMismatch => f.write_str("linkability tag mismatch"),
+            Error::InvalidData => f.write_str("invalid data"),
+            Error::InvalidEncoding => f.write_str("invalid encoding"),
+            Error::InvalidPublicKey => f.write_str("invalid public key"),
+            Error::Cancelled => f.write_str("operation cancelled"),
+            Error::IncompatibleFormat => f.write_str("incompatible format"),
+            Error::SrsMismatch => f.write_str("PCS parameters mismatch"),
+            Error::RingCommitmentMismatch => f.write_str("ring commitment mismatch"),
+            Error::RingTooLarge { expected, actual } => {
+                write!(f, "ring too large: max size {expected}, got {actual}")
+            }
+            Error::SrsTooSmall { expected, actual } => {
+                write!(f, "SRS too small: {expected} G1 powers required, got {actual}")
+            }
+            Error::KeyIndexOutOfBounds { expected, actual } => {
+                write!(f, "key index {actual} out of ring bounds (size {expected})")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 impl From<ark_serialize::SerializationError> for Error {
     fn from(_err: ark_serialize::SerializationError) -> Self {
-        Error::InvalidData
+        Error::InvalidEncoding
     }
 }
 
@@ -79,13 +189,33 @@ impl From<ark_serialize::SerializationError> for Error {
 /// the default methods implementations.
 pub trait Suite: Copy {
     /// Suite identifier (aka `suite_string` in RFC-9381)
+    ///
+    /// Arbitrary length byte string, prepended to all the suite hashes for domain
+    /// separation. Must be at most 255 bytes long, as its length is encoded as a
+    /// single byte by the envelope encodings (see `envelope` module). This is
+    /// enforced at compile time.
     const SUITE_ID: &'static [u8];
 
     /// Challenge encoded length.
     ///
-    /// Must be at least equal to the Hash length.
+    /// Must be at most equal to both the Hash length and the scalar encoded length.
+    /// Proofs encode only the `CHALLENGE_LEN` least significant bytes of the
+    /// challenge, thus `Suite::challenge` output must fit in this length.
     const CHALLENGE_LEN: usize;
 
+    /// Application context (aka personalization string).
+    ///
+    /// Mixed into the challenge and nonce generation, thus binding the proofs to
+    /// the deploying protocol: applications sharing the same suite but using a
+    /// different context don't accept each other proofs. Must be at most 255 bytes
+    /// long, as its length is encoded as a single byte. This is enforced at compile
+    /// time.
+    ///
+    /// Defaults to the empty string, which leaves the procedures unchanged (as
+    /// required by the RFC-9381 test vectors). Suites overriding `challenge` or
+    /// `nonce` are responsible for mixing it in.
+    const CONTEXT: &'static [u8] = b"";
+
     /// Curve point in affine representation.
     ///
     /// The point is guaranteed to be in the correct prime order subgroup
@@ -95,6 +225,10 @@ pub trait Suite: Copy {
     /// Overarching hasher.
     ///
     /// Used wherever an hash is required: nonce, challenge, MAC, etc.
+    ///
+    /// Any output length is supported (see `HashOutput`), e.g. 32 bytes digests
+    /// as SHA-256 or Keccak-256. Hash output length (`hLen`) is given by
+    /// `Digest::output_size`.
     type Hasher: Digest;
 
     /// Overarching codec.
@@ -112,11 +246,12 @@ pub trait Suite: Copy {
     /// The algorithm generate the nonce value in a deterministic
     /// pseudorandom fashion.
     ///
-    /// `Hasher` output **MUST** be be at least 64 bytes.
-    ///
-    /// # Panics
+    /// Suites whose specification requires a different procedure override this
+    /// function. E.g. `utils::nonce_rfc_6979` implements the variant described by
+    /// section 5.4.2.1 (requires the `rfc-6979` feature).
     ///
-    /// This function panics if `Hasher` output is less than 64 bytes.
+    /// The procedure is specified for 64 bytes `Hasher` outputs. Shorter outputs
+    /// are supported as described by `utils::nonce_rfc_8032`.
     #[inline(always)]
     fn nonce(sk: &ScalarField<Self>, pt: Input<Self>) -> ScalarField<Self> {
         utils::nonce_rfc_8032::<Self>(sk, &pt.0)
@@ -128,6 +263,9 @@ pub trait Suite: Copy {
     ///
     /// This implementation extends the RFC procedure to allow adding
     /// some optional additional data too the hashing procedure.
+    ///
+    /// Suites requiring different domain separators or truncation rules
+    /// override this function (e.g. hashing to the full scalar field).
     #[inline(always)]
     fn challenge(pts: &[&AffinePoint<Self>], ad: &[u8]) -> ScalarField<Self> {
         utils::challenge_rfc_9381::<Self>(pts, ad)
@@ -136,6 +274,11 @@ pub trait Suite: Copy {
     /// Hash data to a curve point.
     ///
     /// By default uses "try and increment" method described by RFC 9381.
+    ///
+    /// Suites declare a different mapping by overriding this function with one of:
+    /// - `utils::hash_to_curve_tai_rfc_9381`: try and increment (any curve).
+    /// - `utils::hash_to_curve_ell2_rfc_9380`: Elligator2 (Twisted Edwards curves).
+    /// - `utils::hash_to_curve_sswu_nu_rfc_9380`: XMD + Simplified SWU (Weierstrass curves).
     #[inline(always)]
     fn data_to_point(data: &[u8]) -> Option<AffinePoint<Self>> {
         utils::hash_to_curve_tai_rfc_9381::<Self>(data)
@@ -143,10 +286,15 @@ pub trait Suite: Copy {
 
     /// Map the point to a hash value using `Self::Hasher`.
     ///
-    /// By default uses the algorithm described by RFC 9381.
+    /// By default uses the algorithm described by RFC 9381, including the
+    /// multiplication by the cofactor (see
+    /// `utils::point_to_hash_rfc_9381_with_cofactor`).
+    ///
+    /// Suites whose published test vectors predate the cofactor clearing override
+    /// this to hash the point as is (`utils::point_to_hash_rfc_9381`).
     #[inline(always)]
     fn point_to_hash(pt: &AffinePoint<Self>) -> HashOutput<Self> {
-        utils::point_to_hash_rfc_9381::<Self>(pt)
+        utils::point_to_hash_rfc_9381_with_cofactor::<Self>(pt, true)
     }
 
     /// Generator used through all the suite.
@@ -156,10 +304,37 @@ pub trait Suite: Copy {
     fn generator() -> AffinePoint<Self> {
         Self::Affine::generator()
     }
+
+    /// Scalar from little endian bytes of arbitrary length, reduced modulo the
+    /// group order.
+    ///
+    /// Intended for hash outputs (e.g. secret derivation from seed, as in RFC-8032).
+    /// Doesn't depend on `Self::Codec`, use `codec::scalar_decode` for the suite
+    /// `string_to_int` procedure.
+    #[inline(always)]
+    fn scalar_from_le_bytes_mod_order(buf: &[u8]) -> ScalarField<Self> {
+        ScalarField::<Self>::from_le_bytes_mod_order(buf)
+    }
+
+    /// Scalar from integer.
+    #[inline(always)]
+    fn scalar_from_u64(value: u64) -> ScalarField<Self> {
+        ScalarField::<Self>::from(value)
+    }
+
+    /// Scalar encoding using `Self::Codec` (aka `int_to_string` in RFC-9381).
+    ///
+    /// The encoding has fixed length and the suite codec endianness.
+    #[inline(always)]
+    fn scalar_to_bytes(sc: &ScalarField<Self>) -> Vec<u8> {
+        codec::scalar_encode::<Self>(sc)
+    }
 }
 
 /// Secret key.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Equality is evaluated in constant time with respect to the secret scalar.
+#[derive(Clone)]
 pub struct Secret<S: Suite> {
     // Secret scalar.
     pub scalar: ScalarField<S>,
@@ -187,14 +362,62 @@ impl<'a, S: Suite + 'a> Decoder<'a> for Secret<S> {
         // Decode the tuple containing the scalar and public parts
         let (scalar_buf, public_term): (Vec<u8>, Term<'a>) = term.decode()?;
 
-        // Decode the scalar from the scalar buffer
-        let scalar = S::Codec::scalar_decode(&scalar_buf);
+        // Strictly decode the scalar from the scalar buffer
+        let secret = Secret::<S>::try_from(&scalar_buf[..])
+            .map_err(|_| rustler::Error::Atom("deserialization_failed"))?;
 
         // Decode the public key using the existing Decoder implementation for Public<S>
-        let public = public_term.decode()?;
+        let public: Public<S> = public_term.decode()?;
 
-        // Construct and return the Secret<S> instance
-        Ok(Secret { scalar, public })
+        // The public key is required to match the scalar
+        if public != secret.public {
+            return Err(rustler::Error::Atom("deserialization_failed"));
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(feature = "zeroize-secrets")]
+impl<S: Suite> Zeroize for Secret<S> {
+    /// Zeroize the secret scalar. The public key is left untouched.
+    fn zeroize(&mut self) {
+        self.scalar.zeroize()
+    }
+}
+
+#[cfg(feature = "zeroize-secrets")]
+impl<S: Suite> zeroize::ZeroizeOnDrop for Secret<S> {}
+
+impl<S: Suite> core::fmt::Debug for Secret<S> {
+    /// Only the public key is shown, the secret scalar is redacted.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Secret")
+            .field("scalar", &"<redacted>")
+            .field("public", &self.public)
+            .finish()
+    }
+}
+
+impl<S: Suite> PartialEq for Secret<S> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut a = codec::scalar_encode::<S>(&self.scalar);
+        let mut b = codec::scalar_encode::<S>(&other.scalar);
+        let eq = utils::ct_eq(&a, &b);
+        a.as_mut_slice().zeroize();
+        b.as_mut_slice().zeroize();
+        eq
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<S: Suite> subtle::ConstantTimeEq for Secret<S> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        let mut a = codec::scalar_encode::<S>(&self.scalar);
+        let mut b = codec::scalar_encode::<S>(&other.scalar);
+        let eq = subtle::ConstantTimeEq::ct_eq(&a[..], &b[..]);
+        a.as_mut_slice().zeroize();
+        b.as_mut_slice().zeroize();
+        eq
     }
 }
 
@@ -237,10 +460,41 @@ impl<S: Suite> ark_serialize::Valid for Secret<S> {
     }
 }
 
+impl<S: Suite> TryFrom<&[u8]> for Secret<S> {
+    type Error = Error;
+
+    /// Decode the secret scalar encoded using `Suite::Codec`.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` is not a canonical scalar encoding
+    /// and `Error::InvalidData` if the scalar is zero.
+    fn try_from(buf: &[u8]) -> Result<Self, Self::Error> {
+        let scalar = codec::scalar_decode_strict::<S>(buf)?;
+        if ark_std::Zero::is_zero(&scalar) {
+            return Err(Error::InvalidData);
+        }
+        Ok(Self::from_scalar(scalar))
+    }
+}
+
+impl<S: Suite> From<Secret<S>> for Vec<u8> {
+    /// Encode the secret scalar using `Suite::Codec`.
+    fn from(secret: Secret<S>) -> Self {
+        codec::scalar_encode::<S>(&secret.scalar)
+    }
+}
+
 impl<S: Suite> Secret<S> {
-    /// Construct a `Secret` from the given scalar.
+    /// Construct a `Secret` from the given raw scalar.
+    ///
+    /// Intended for importing keys generated by other libraries. The scalar is
+    /// used as is, thus the caller is responsible for it being uniformly random
+    /// and not zero. Keys generated by this crate should be constructed via
+    /// `from_seed` or `generate` instead.
+    ///
+    /// To import the scalar encoding use `Secret::try_from`, which decodes the
+    /// scalar via `Suite::Codec` and rejects non canonical encodings and zero.
     pub fn from_scalar(scalar: ScalarField<S>) -> Self {
-        let public = Public((S::generator() * scalar).into_affine());
+        let public = Public::from_secret_scalar(&scalar);
         Self { scalar, public }
     }
 
@@ -248,8 +502,9 @@ impl<S: Suite> Secret<S> {
     ///
     /// The `seed` is hashed using the `Suite::hash` to construct the secret scalar.
     pub fn from_seed(seed: &[u8]) -> Self {
-        let bytes = utils::hash::<S::Hasher>(seed);
+        let mut bytes = utils::hash::<S::Hasher>(seed);
         let scalar = ScalarField::<S>::from_le_bytes_mod_order(&bytes[..]);
+        bytes.as_mut_slice().zeroize();
         Self::from_scalar(scalar)
     }
 
@@ -257,7 +512,25 @@ impl<S: Suite> Secret<S> {
     pub fn from_rand(rng: &mut impl ark_std::rand::RngCore) -> Self {
         let mut seed = [0u8; 32];
         rng.fill_bytes(&mut seed);
-        Self::from_seed(&seed)
+        let secret = Self::from_seed(&seed);
+        seed.zeroize();
+        secret
+    }
+
+    /// Generate a new `Secret` using the provided cryptographically secure
+    /// randomness source.
+    ///
+    /// The secret scalar is uniformly sampled. Prefer this over `from_seed` unless
+    /// the seed is a high entropy secret.
+    pub fn generate(rng: &mut (impl ark_std::rand::CryptoRng + ark_std::rand::RngCore)) -> Self {
+        use ark_std::UniformRand;
+        Self::from_scalar(ScalarField::<S>::rand(rng))
+    }
+
+    /// Generate a new `Secret` using the OS randomness source.
+    #[cfg(feature = "getrandom")]
+    pub fn random() -> Self {
+        Self::generate(&mut ark_std::rand::rngs::OsRng)
     }
 
     /// Get the associated public key.
@@ -271,10 +544,263 @@ impl<S: Suite> Secret<S> {
     }
 }
 
+impl<S: Suite> Secret<S> {
+    /// Convert to the secret key of another suite over the same curve.
+    ///
+    /// Allows to reuse a key with a suite differing only in hasher, codec or
+    /// hash-to-curve procedure (e.g. Bandersnatch SHA-512 and Poseidon variants).
+    /// The secret scalar and the public key are preserved.
+    ///
+    /// Returns `Error::IncompatibleFormat` if the suites use different generators,
+    /// as the public key would change.
+    pub fn try_into_suite<T: Suite<Affine = S::Affine>>(&self) -> Result<Secret<T>, Error> {
+        let public = self.public.try_into_suite::<T>()?;
+        Ok(Secret {
+            scalar: self.scalar,
+            public,
+        })
+    }
+}
+
+#[cfg(feature = "bip39")]
+impl<S: Suite> Secret<S> {
+    /// Construct a `Secret` from a BIP39 mnemonic phrase and password.
+    ///
+    /// The 64 bytes BIP39 seed is used as `from_seed` input. Phrase and password
+    /// are expected to be NFKD normalized (always the case for ASCII strings).
+    ///
+    /// Returns `Error::InvalidData` if the phrase is not a valid mnemonic.
+    pub fn from_mnemonic(phrase: &str, password: &str) -> Result<Self, Error> {
+        let mnemonic =
+            bip39::Mnemonic::parse_normalized(phrase).map_err(|_| Error::InvalidData)?;
+        let mut seed = mnemonic.to_seed_normalized(password);
+        let secret = Self::from_seed(&seed);
+        seed.zeroize();
+        Ok(secret)
+    }
+
+    /// Generate a new `Secret` together with its 24 words BIP39 mnemonic phrase.
+    ///
+    /// The secret can be recovered from the phrase and password via `from_mnemonic`.
+    pub fn generate_with_mnemonic(
+        rng: &mut (impl ark_std::rand::CryptoRng + ark_std::rand::RngCore),
+        password: &str,
+    ) -> (Self, ark_std::string::String) {
+        use ark_std::string::ToString;
+        let mut entropy = [0_u8; 32];
+        rng.fill_bytes(&mut entropy);
+        let mnemonic =
+            bip39::Mnemonic::from_entropy(&entropy).expect("32 bytes is valid entropy length");
+        entropy.zeroize();
+        let phrase = mnemonic.to_string();
+        let mut seed = mnemonic.to_seed_normalized(password);
+        let secret = Self::from_seed(&seed);
+        seed.zeroize();
+        (secret, phrase)
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<S: Suite> Secret<S> {
+    /// Construct a `Secret` from a seed held by a `secrecy::SecretSlice`.
+    ///
+    /// Same as `from_seed`.
+    pub fn from_secret_seed(seed: &secrecy::SecretSlice<u8>) -> Self {
+        use secrecy::ExposeSecret;
+        Self::from_seed(seed.expose_secret())
+    }
+
+    /// Construct a `Secret` from a scalar held by a `secrecy::SecretBox`.
+    ///
+    /// Same as `from_scalar`.
+    pub fn from_secret_box(scalar: &secrecy::SecretBox<ScalarField<S>>) -> Self {
+        use secrecy::ExposeSecret;
+        Self::from_scalar(*scalar.expose_secret())
+    }
+
+    /// Copy of the secret scalar held by a `secrecy::SecretBox`.
+    ///
+    /// The box is zeroized on drop and redacted by `Debug`.
+    pub fn to_secret_box(&self) -> secrecy::SecretBox<ScalarField<S>> {
+        secrecy::SecretBox::new(ark_std::boxed::Box::new(self.scalar))
+    }
+
+    /// Secret scalar encoding held by a `secrecy::SecretSlice`.
+    ///
+    /// The encoding is the one accepted by `Secret::try_from`.
+    pub fn to_secret_bytes(&self) -> secrecy::SecretSlice<u8> {
+        codec::scalar_encode::<S>(&self.scalar).into()
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<S: Suite> secrecy::ExposeSecret<ScalarField<S>> for Secret<S> {
+    fn expose_secret(&self) -> &ScalarField<S> {
+        &self.scalar
+    }
+}
+
+/// Secret and public key pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keypair<S: Suite> {
+    secret: Secret<S>,
+    public: Public<S>,
+}
+
+impl<S: Suite> Keypair<S> {
+    /// Construct a `Keypair` from the given secret.
+    pub fn from_secret(secret: Secret<S>) -> Self {
+        let public = secret.public();
+        Self { secret, public }
+    }
+
+    /// Construct a `Keypair` from the given seed (see `Secret::from_seed`).
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self::from_secret(Secret::from_seed(seed))
+    }
+
+    /// Generate a new `Keypair` using the OS randomness source.
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> Self {
+        Self::from_secret(Secret::random())
+    }
+
+    /// Get the secret key.
+    pub fn secret(&self) -> &Secret<S> {
+        &self.secret
+    }
+
+    /// Get the public key.
+    pub fn public(&self) -> Public<S> {
+        self.public
+    }
+}
+
+impl<S: Suite> From<Secret<S>> for Keypair<S> {
+    fn from(secret: Secret<S>) -> Self {
+        Self::from_secret(secret)
+    }
+}
+
 /// Public key generic over the cipher suite.
-#[derive(Debug, Copy, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+///
+/// Deserialization via `CanonicalDeserialize` performs the same checks as
+/// `Public::validate` when requested.
+///
+/// Hashing and ordering are based on the `Suite::Codec` point encoding, thus
+/// public keys can be used as map keys and sorted consistently across platforms.
+#[derive(Copy, Clone, PartialEq, Eq, CanonicalSerialize)]
 pub struct Public<S: Suite>(pub AffinePoint<S>);
 
+utils::bytes_encoding_impl!(
+    Public<S: Suite>,
+    Public::to_bytes,
+    Public::from_bytes,
+    codec::point_encoded_len::<S>
+);
+
+impl<S: Suite> Public<S> {
+    /// Public key corresponding to the given raw secret scalar (`x * G`).
+    ///
+    /// Matches `Secret::from_scalar(scalar).public()` without constructing the
+    /// secret. The scalar is not zeroized, the caller owns it.
+    pub fn from_secret_scalar(scalar: &ScalarField<S>) -> Self {
+        Public((S::generator() * scalar).into_affine())
+    }
+
+    /// Check that the public key is a valid curve point, is not the identity
+    /// and is in the prime order subgroup.
+    ///
+    /// Returns `Error::InvalidPublicKey` if any of the checks fails.
+    pub fn validate(&self) -> Result<(), Error> {
+        use ark_serialize::Valid;
+        Valid::check(self).map_err(|_| Error::InvalidPublicKey)
+    }
+
+    /// Encode the public key point using `Suite::Codec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        codec::point_encode::<S>(&self.0)
+    }
+
+    /// Decode a public key previously encoded via `to_bytes`.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` is not a canonical point encoding
+    /// and `Error::InvalidPublicKey` if the decoded public key doesn't pass `validate`.
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        let public = Self::from_bytes_unchecked(buf)?;
+        if public.to_bytes() != buf {
+            return Err(Error::InvalidEncoding);
+        }
+        public.validate()?;
+        Ok(public)
+    }
+
+    /// Decode a public key without canonicity and validity checks.
+    ///
+    /// Use `from_bytes` unless the encoding comes from a trusted source.
+    pub fn from_bytes_unchecked(buf: &[u8]) -> Result<Self, Error> {
+        codec::point_decode::<S>(buf).map(Public)
+    }
+
+    /// Convert to the public key of another suite over the same curve.
+    ///
+    /// Returns `Error::IncompatibleFormat` if the suites use different generators
+    /// (see `Secret::try_into_suite`).
+    pub fn try_into_suite<T: Suite<Affine = S::Affine>>(&self) -> Result<Public<T>, Error> {
+        if S::generator() != T::generator() {
+            return Err(Error::IncompatibleFormat);
+        }
+        Ok(Public(self.0))
+    }
+}
+
+impl<S: Suite> core::hash::Hash for Public<S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state)
+    }
+}
+
+impl<S: Suite> PartialOrd for Public<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Suite> Ord for Public<S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_bytes().cmp(&other.to_bytes())
+    }
+}
+
+impl<S: Suite> ark_serialize::Valid for Public<S> {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        // Checks the point to be on the curve and in the prime order subgroup.
+        ark_serialize::Valid::check(&self.0)?;
+        if self.0.is_zero() {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<S: Suite> CanonicalDeserialize for Public<S> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, ark_serialize::SerializationError> {
+        let public = Public(AffinePoint::<S>::deserialize_with_mode(
+            reader,
+            compress,
+            ark_serialize::Validate::No,
+        )?);
+        if validate == ark_serialize::Validate::Yes {
+            ark_serialize::Valid::check(&public)?;
+        }
+        Ok(public)
+    }
+}
+
 impl<S: Suite> Encoder for Public<S> {
     fn encode<'b>(&self, env: rustler::Env<'b>) -> Term<'b> {
         let mut buf = Vec::new();
@@ -286,42 +812,199 @@ impl<S: Suite> Encoder for Public<S> {
 impl<'a, S: Suite + 'a> Decoder<'a> for Public<S> {
     fn decode(term: Term<'a>) -> NifResult<Self> {
         let binary: Vec<u8> = term.decode()?;
-        let point = S::Codec::point_decode(&binary[..])
-            .map_err(|_| rustler::Error::Atom("deserialization_failed"))?;
-        Ok(Public(point))
+        Public::from_bytes(&binary[..]).map_err(|_| rustler::Error::Atom("deserialization_failed"))
     }
 }
 
 /// VRF input point generic over the cipher suite.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Input<S: Suite>(pub AffinePoint<S>);
 
+utils::bytes_encoding_impl!(
+    Input<S: Suite>,
+    Input::to_bytes,
+    Input::from_bytes,
+    Input::<S>::encoded_len
+);
+
 impl<S: Suite> Input<S> {
     /// Construct from [`Suite::data_to_point`].
     pub fn new(data: &[u8]) -> Option<Self> {
         S::data_to_point(data).map(Input)
     }
 
+    /// Construct from [`Suite::data_to_point`] salted with the public key.
+    ///
+    /// As specified by RFC-9381, `encode_to_curve_salt = point_to_string(PK)` and
+    /// the data mapped to the curve is `salt || alpha`.
+    pub fn new_salted(public: &Public<S>, alpha: &[u8]) -> Option<Self> {
+        let mut buf = codec::point_encode::<S>(&public.0);
+        buf.extend_from_slice(alpha);
+        Self::new(&buf)
+    }
+
     /// Construct from inner affine point.
     pub fn from(value: <S as Suite>::Affine) -> Self {
         Input(value)
     }
+
+    /// Length of the encoding produced by `to_bytes`.
+    pub fn encoded_len() -> usize {
+        codec::point_encoded_len::<S>()
+    }
+
+    /// Encode using `Suite::Codec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        codec::point_encode::<S>(&self.0)
+    }
+
+    /// Decode an input previously encoded via `to_bytes`.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` is not the canonical encoding of a
+    /// point in the prime order subgroup and `Error::InvalidData` if the point is
+    /// the identity.
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        point_from_bytes::<S>(buf).map(Input)
+    }
+}
+
+// Strict point decoding shared by `Input` and `Output`.
+fn point_from_bytes<S: Suite>(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+    let pt = codec::point_decode_strict::<S>(buf)?;
+    if pt.is_zero() {
+        return Err(Error::InvalidData);
+    }
+    Ok(pt)
+}
+
+/// Incremental VRF input construction.
+///
+/// Data can be provided in chunks, as it comes. On finalization the concatenated
+/// chunks are mapped to the curve via `Input::new`, thus the resulting input is the
+/// one constructed via `Input::new` over the same data.
+///
+/// As `Suite::data_to_point` requires the whole data, this is accumulated until
+/// finalization.
+pub struct InputHasher<S: Suite>(Vec<u8>, core::marker::PhantomData<S>);
+
+impl<S: Suite> InputHasher<S> {
+    /// Construct a new hasher.
+    pub fn new() -> Self {
+        Self(Vec::new(), core::marker::PhantomData)
+    }
+
+    /// Absorb a chunk of data.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.0.extend_from_slice(data.as_ref());
+    }
+
+    /// Absorb a chunk of data in a chained manner.
+    pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+        self.update(data);
+        self
+    }
+
+    /// Construct the input from the absorbed data.
+    pub fn finalize(self) -> Option<Input<S>> {
+        Input::new(&self.0)
+    }
+}
+
+impl<S: Suite> Default for InputHasher<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Suite> ark_std::io::Write for InputHasher<S> {
+    fn write(&mut self, buf: &[u8]) -> ark_std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ark_std::io::Result<()> {
+        Ok(())
+    }
 }
 
 /// VRF output point generic over the cipher suite.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Output<S: Suite>(pub AffinePoint<S>);
 
+utils::bytes_encoding_impl!(
+    Output<S: Suite>,
+    Output::to_bytes,
+    Output::from_bytes,
+    Output::<S>::encoded_len
+);
+
+/// Compares the encoded outputs.
+///
+/// To compare the VRF output strings (i.e. `Output::hash`) or seeds use the
+/// `subtle::ConstantTimeEq` implementation for byte slices.
+#[cfg(feature = "subtle")]
+impl<S: Suite> subtle::ConstantTimeEq for Output<S> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        let a = codec::point_encode::<S>(&self.0);
+        let b = codec::point_encode::<S>(&other.0);
+        subtle::ConstantTimeEq::ct_eq(&a[..], &b[..])
+    }
+}
+
 impl<S: Suite> Output<S> {
     /// Construct from inner affine point.
     pub fn from(value: AffinePoint<S>) -> Self {
         Output(value)
     }
 
+    /// Length of the encoding produced by `to_bytes`.
+    pub fn encoded_len() -> usize {
+        codec::point_encoded_len::<S>()
+    }
+
+    /// Encode using `Suite::Codec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        codec::point_encode::<S>(&self.0)
+    }
+
+    /// Decode an output previously encoded via `to_bytes`.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` is not the canonical encoding of a
+    /// point in the prime order subgroup and `Error::InvalidData` if the point is
+    /// the identity.
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        point_from_bytes::<S>(buf).map(Output)
+    }
+
     /// Hash using `[Suite::point_to_hash]`.
+    ///
+    /// This is the VRF output string (aka `beta_string` in RFC-9381).
     pub fn hash(&self) -> HashOutput<S> {
         S::point_to_hash(&self.0)
     }
+
+    /// Expand the output to an arbitrary length string using the XOF `X`.
+    ///
+    /// The string is `X(suite_string || 0x04 || beta_string || 0x00)` squeezed to
+    /// `len` bytes, with `beta_string` the output of `Output::hash`. Thus shorter
+    /// expansions are prefixes of the longer ones.
+    pub fn expand<X>(&self, len: usize) -> Vec<u8>
+    where
+        X: Default + digest::Update + digest::ExtendableOutput,
+    {
+        use digest::{ExtendableOutput, Update, XofReader};
+        const DOM_SEP_START: u8 = 0x04;
+        const DOM_SEP_END: u8 = 0x00;
+        let mut buf = ark_std::vec![0; len];
+        X::default()
+            .chain(S::SUITE_ID)
+            .chain([DOM_SEP_START])
+            .chain(self.hash())
+            .chain([DOM_SEP_END])
+            .finalize_xof()
+            .read(&mut buf);
+        buf
+    }
 }
 
 #[cfg(test)]
@@ -340,4 +1023,519 @@ mod tests {
         let expected = "0245a793d85347ca3c056f8c8f42f1049a310fabff6933b9eae592541a545cb8";
         assert_eq!(expected, hex::encode(output.hash()));
     }
+
+    #[cfg(feature = "zeroize-secrets")]
+    #[test]
+    fn secret_zeroize() {
+        use ark_std::Zero;
+        use zeroize::Zeroize;
+        let mut secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        secret.zeroize();
+        assert!(secret.scalar.is_zero());
+        assert_eq!(secret.public(), public);
+    }
+
+    #[test]
+    fn secret_generate() {
+        use ark_std::rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+        let secret = Secret::generate(&mut rng);
+        assert_ne!(Secret::generate(&mut rng), secret);
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+        assert_eq!(Secret::generate(&mut rng), secret);
+
+        #[cfg(feature = "getrandom")]
+        assert_ne!(Secret::random(), Secret::random());
+    }
+
+    #[cfg(feature = "bip39")]
+    #[test]
+    fn secret_from_mnemonic() {
+        use ark_std::rand::SeedableRng;
+
+        // BIP39 reference vector
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandon about";
+        let seed = hex::decode(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+             1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        )
+        .unwrap();
+        let secret = Secret::from_mnemonic(phrase, "TREZOR").unwrap();
+        assert_eq!(secret, Secret::from_seed(&seed));
+        assert!(Secret::from_mnemonic("abandon about", "TREZOR").is_err());
+
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+        let (secret, phrase) = Secret::generate_with_mnemonic(&mut rng, "foo");
+        assert_eq!(phrase.split(' ').count(), 24);
+        assert_eq!(Secret::from_mnemonic(&phrase, "foo").unwrap(), secret);
+        assert_ne!(Secret::from_mnemonic(&phrase, "bar").unwrap(), secret);
+    }
+
+    #[test]
+    fn public_validate() {
+        use crate::{suites::testing::{AffinePoint, BaseField, Public}, Error};
+        use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+        use ark_std::{One, Zero};
+
+        let public = Secret::from_seed(TEST_SEED).public();
+        assert!(public.validate().is_ok());
+        assert_eq!(Public::from_bytes(&public.to_bytes()).unwrap(), public);
+
+        // Identity
+        let identity = Public(AffinePoint::zero());
+        assert_eq!(identity.validate(), Err(Error::InvalidPublicKey));
+        assert!(Public::from_bytes(&identity.to_bytes()).is_err());
+        assert!(Public::from_bytes_unchecked(&identity.to_bytes()).is_ok());
+
+        // Order 2 point, not in the prime order subgroup.
+        let torsion = Public(AffinePoint::new_unchecked(BaseField::zero(), -BaseField::one()));
+        assert!(torsion.validate().is_err());
+        assert!(Public::from_bytes(&torsion.to_bytes()).is_err());
+        let mut buf = Vec::new();
+        torsion.serialize_compressed(&mut buf).unwrap();
+        assert!(Public::deserialize_compressed(&mut &buf[..]).is_err());
+        assert!(Public::deserialize_compressed_unchecked(&mut &buf[..]).is_ok());
+
+        // Not on curve
+        let invalid = Public(AffinePoint::new_unchecked(BaseField::one(), BaseField::one()));
+        assert!(invalid.validate().is_err());
+    }
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn hex_fmt_roundtrip() {
+        use crate::ietf::{Prover as _, Verifier as _};
+        use crate::suites::testing::{IetfProof, Output, Public, TestSuite};
+        use ark_std::{format, string::ToString};
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::new(b"foo").unwrap();
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, b"bar");
+        let signature = secret.sign(input, b"bar");
+
+        let s = public.to_string();
+        assert_eq!(s, hex::encode(public.to_bytes()));
+        assert_eq!(s.parse::<Public>().unwrap(), public);
+        assert_eq!(format!("{:?}", public), format!("Public({})", s));
+        assert_eq!(input.to_string().parse::<Input>().unwrap(), input);
+        assert_eq!(output.to_string().parse::<Output>().unwrap(), output);
+        let parsed = proof.to_string().parse::<IetfProof>().unwrap();
+        assert!(public.verify(input, output, b"bar", &parsed).is_ok());
+        let parsed = signature
+            .to_string()
+            .parse::<crate::pedersen::Signature<TestSuite>>()
+            .unwrap();
+        assert!(parsed.verify(input, b"bar").is_ok());
+
+        // Secret scalar is not leaked.
+        let scalar = hex::encode(crate::codec::scalar_encode::<TestSuite>(&secret.scalar));
+        assert!(!format!("{:?}", secret).contains(&scalar));
+
+        assert!("".parse::<Public>().is_err());
+        assert!(s[1..].parse::<Public>().is_err());
+        assert!(format!("{}zz", &s[2..]).parse::<Public>().is_err());
+        assert!(format!("{}00", s).parse::<Public>().is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "pedersen"))]
+    #[test]
+    fn serde_roundtrip() {
+        use crate::suites::testing::{Output, Public, TestSuite};
+        type Signature = crate::pedersen::Signature<TestSuite>;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::new(b"foo").unwrap();
+        let signature = secret.sign(input, b"bar");
+
+        let json = serde_json::to_string(&public).unwrap();
+        assert_eq!(json, ark_std::format!("\"{}\"", public));
+        assert_eq!(serde_json::from_str::<Public>(&json).unwrap(), public);
+
+        let json = serde_json::to_string(&signature.output()).unwrap();
+        assert_eq!(serde_json::from_str::<Output>(&json).unwrap(), signature.output());
+
+        let json = serde_json::to_string(&signature).unwrap();
+        let decoded = serde_json::from_str::<Signature>(&json).unwrap();
+        assert!(decoded.verify(input, b"bar").is_ok());
+
+        assert!(serde_json::from_str::<Public>("\"zz\"").is_err());
+        assert!(serde_json::from_str::<Public>("[1, 2]").is_err());
+    }
+
+    #[cfg(all(feature = "scale", feature = "pedersen"))]
+    #[test]
+    fn scale_roundtrip() {
+        use crate::suites::testing::{Public, TestSuite};
+        use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+        type Signature = crate::pedersen::Signature<TestSuite>;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::new(b"foo").unwrap();
+        let signature = secret.sign(input, b"bar");
+
+        let buf = public.encode();
+        assert_eq!(buf, public.to_bytes());
+        assert_eq!(buf.len(), Public::max_encoded_len());
+        assert_eq!(Public::decode(&mut &buf[..]).unwrap(), public);
+        assert!(Public::decode(&mut &buf[1..]).is_err());
+
+        let buf = signature.encode();
+        assert_eq!(buf.len(), Signature::max_encoded_len());
+        let decoded = Signature::decode(&mut &buf[..]).unwrap();
+        assert!(decoded.verify(input, b"bar").is_ok());
+    }
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn prelude_works() {
+        use crate::prelude::{IetfProver, IetfVerifier, PedersenProver, PedersenVerifier};
+        use crate::suites::testing::{Keypair, PedersenSignature, Public};
+
+        let keypair = Keypair::from_seed(TEST_SEED);
+        let input = Input::new(b"foo").unwrap();
+        let output = keypair.secret().output(input);
+
+        let proof = IetfProver::prove(keypair.secret(), input, output, b"bar");
+        assert!(IetfVerifier::verify(&keypair.public(), input, output, b"bar", &proof).is_ok());
+
+        let (proof, _) = PedersenProver::prove(keypair.secret(), input, output, b"bar");
+        assert!(<Public as PedersenVerifier<_>>::verify(input, output, b"bar", &proof).is_ok());
+
+        let signature: PedersenSignature = keypair.secret().sign(input, b"bar");
+        assert!(signature.verify(input, b"bar").is_ok());
+    }
+
+    #[test]
+    fn input_output_bytes() {
+        use crate::suites::testing::{AffinePoint, Output};
+        use crate::Error;
+        use ark_ec::AffineRepr;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let input = Input::new(b"foo").unwrap();
+        let output = secret.output(input);
+
+        let buf = input.to_bytes();
+        assert_eq!(buf.len(), Input::encoded_len());
+        assert_eq!(Input::from_bytes(&buf).unwrap(), input);
+        assert_eq!(
+            Input::from_bytes(&buf[1..]).unwrap_err(),
+            Error::InvalidEncoding
+        );
+
+        let buf = output.to_bytes();
+        assert_eq!(buf.len(), Output::encoded_len());
+        assert_eq!(Output::from_bytes(&buf).unwrap(), output);
+
+        let identity = Output::from(AffinePoint::zero()).to_bytes();
+        assert_eq!(Output::from_bytes(&identity).unwrap_err(), Error::InvalidData);
+    }
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn try_from_bytes() {
+        use crate::prelude::{IetfProver, PedersenProver};
+        use crate::suites::testing::{IetfProof, Output, PedersenProof, PedersenSignature, Public};
+        use crate::Error;
+        use ark_std::vec::Vec;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let buf: Vec<u8> = secret.clone().into();
+        assert_eq!(Secret::try_from(&buf[..]).unwrap(), secret);
+        let zero = ark_std::vec![0; buf.len()];
+        assert_eq!(Secret::try_from(&zero[..]).unwrap_err(), Error::InvalidData);
+        assert_eq!(
+            Secret::try_from(&buf[1..]).unwrap_err(),
+            Error::InvalidEncoding
+        );
+
+        let public = secret.public();
+        let buf: Vec<u8> = public.into();
+        assert_eq!(Public::try_from(&buf[..]).unwrap(), public);
+
+        let input = Input::new(b"foo").unwrap();
+        let buf: Vec<u8> = input.into();
+        assert_eq!(Input::try_from(&buf[..]).unwrap(), input);
+
+        let output = secret.output(input);
+        let buf: Vec<u8> = output.into();
+        assert_eq!(Output::try_from(&buf[..]).unwrap(), output);
+
+        let proof = IetfProver::prove(&secret, input, output, b"bar");
+        let buf: Vec<u8> = proof.into();
+        assert_eq!(Vec::from(IetfProof::try_from(&buf[..]).unwrap()), buf);
+        let mut extra = buf.clone();
+        extra.push(0);
+        assert_eq!(
+            IetfProof::try_from(&extra[..]).unwrap_err(),
+            Error::InvalidEncoding
+        );
+
+        let (proof, _) = PedersenProver::prove(&secret, input, output, b"bar");
+        let buf: Vec<u8> = proof.into();
+        assert_eq!(Vec::from(PedersenProof::try_from(&buf[..]).unwrap()), buf);
+
+        let signature: PedersenSignature = secret.sign(input, b"bar");
+        let buf: Vec<u8> = signature.into();
+        let signature = PedersenSignature::try_from(&buf[..]).unwrap();
+        assert!(signature.verify(input, b"bar").is_ok());
+        assert_eq!(
+            PedersenSignature::try_from(&buf[..buf.len() - 1]).unwrap_err(),
+            Error::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn raw_scalar_import() {
+        use crate::suites::testing::{Public, ScalarField};
+
+        let scalar = ScalarField::from(42u64);
+        let secret = Secret::from_scalar(scalar);
+        assert_eq!(secret.scalar, scalar);
+        assert_eq!(Public::from_secret_scalar(&scalar), secret.public());
+
+        let other = Secret::from_seed(TEST_SEED);
+        assert_eq!(Secret::from_scalar(other.scalar), other);
+        assert_eq!(Public::from_secret_scalar(&other.scalar), other.public());
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn cross_suite_conversion() {
+        use crate::suites::ed25519::Ed25519Sha512Tai;
+        use crate::suites::testing::TestSuite;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let converted = secret.try_into_suite::<Ed25519Sha512Tai>().unwrap();
+        assert_eq!(converted.scalar, secret.scalar);
+        assert_eq!(converted.public().0, secret.public().0);
+        assert_eq!(
+            secret.public().try_into_suite::<Ed25519Sha512Tai>().unwrap(),
+            converted.public()
+        );
+        assert_eq!(converted.try_into_suite::<TestSuite>().unwrap(), secret);
+
+        #[derive(Clone, Copy)]
+        struct OtherGenerator;
+
+        impl crate::Suite for OtherGenerator {
+            const SUITE_ID: &'static [u8] = b"other-generator";
+            const CHALLENGE_LEN: usize = 16;
+            type Affine = ark_ed25519::EdwardsAffine;
+            type Hasher = sha2::Sha256;
+            type Codec = crate::codec::ArkworksCodec;
+
+            fn generator() -> ark_ed25519::EdwardsAffine {
+                use ark_ec::{AffineRepr, CurveGroup};
+                (ark_ed25519::EdwardsAffine::generator() * ark_ed25519::Fr::from(2)).into_affine()
+            }
+        }
+
+        assert_eq!(
+            secret.try_into_suite::<OtherGenerator>().unwrap_err(),
+            crate::Error::IncompatibleFormat
+        );
+    }
+
+    #[cfg(feature = "ietf")]
+    #[test]
+    fn application_context() {
+        use crate::ietf::{Proof, Prover, Verifier};
+        use crate::{suites::testing::TestSuite, utils, Suite};
+        use ark_std::string::ToString;
+        use core::str::FromStr;
+
+        macro_rules! context_suite {
+            ($name:ident, $context:expr) => {
+                #[derive(Clone, Copy)]
+                struct $name;
+
+                impl Suite for $name {
+                    const SUITE_ID: &'static [u8] = b"ark-ec-vrfs-testing";
+                    const CHALLENGE_LEN: usize = 16;
+                    const CONTEXT: &'static [u8] = $context;
+                    type Affine = ark_ed25519::EdwardsAffine;
+                    type Hasher = sha2::Sha256;
+                    type Codec = crate::codec::ArkworksCodec;
+                }
+            };
+        }
+        context_suite!(NoContext, b"");
+        context_suite!(AppA, b"app-a");
+        context_suite!(AppB, b"app-b");
+
+        let pt = NoContext::generator();
+        let c = utils::challenge_rfc_9381::<NoContext>(&[&pt], b"foo");
+        assert_eq!(c, utils::challenge_rfc_9381::<TestSuite>(&[&pt], b"foo"));
+        assert_ne!(c, utils::challenge_rfc_9381::<AppA>(&[&pt], b"foo"));
+
+        let secret = crate::Secret::<AppA>::from_seed(TEST_SEED);
+        let input = crate::Input::<AppA>::new(b"foo").unwrap();
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, b"bar");
+        assert!(secret.public().verify(input, output, b"bar", &proof).is_ok());
+
+        // Same key and data, different application.
+        let public = crate::Public::<AppB>(secret.public().0);
+        let proof = Proof::<AppB>::from_str(&proof.to_string()).unwrap();
+        let res = public.verify(
+            crate::Input(input.0),
+            crate::Output(output.0),
+            b"bar",
+            &proof,
+        );
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "secrecy")]
+    #[test]
+    fn secrecy_works() {
+        use secrecy::{ExposeSecret, SecretSlice};
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let seed = SecretSlice::from(TEST_SEED.to_vec());
+        assert_eq!(Secret::from_secret_seed(&seed), secret);
+
+        let scalar = secret.to_secret_box();
+        assert_eq!(scalar.expose_secret(), secret.expose_secret());
+        assert_eq!(Secret::from_secret_box(&scalar), secret);
+
+        let bytes = secret.to_secret_bytes();
+        assert_eq!(Secret::try_from(bytes.expose_secret()).unwrap(), secret);
+        assert!(ark_std::format!("{:?}", bytes).contains("REDACTED"));
+    }
+
+    #[test]
+    fn scalar_helpers() {
+        use crate::suites::testing::{ScalarField, TestSuite};
+        use crate::Suite;
+
+        let sc = TestSuite::scalar_from_u64(0x0102);
+        assert_eq!(sc, ScalarField::from(0x0102u64));
+        assert_eq!(TestSuite::scalar_from_le_bytes_mod_order(&[0x02, 0x01]), sc);
+
+        let buf = TestSuite::scalar_to_bytes(&sc);
+        assert_eq!(buf, crate::codec::scalar_encode::<TestSuite>(&sc));
+        assert_eq!(TestSuite::scalar_from_le_bytes_mod_order(&buf), sc);
+
+        // Reduced modulo the group order.
+        let wide = [0xff; 64];
+        let sc = TestSuite::scalar_from_le_bytes_mod_order(&wide);
+        assert_eq!(TestSuite::scalar_to_bytes(&sc).len(), buf.len());
+    }
+
+    #[test]
+    fn error_display() {
+        use crate::{suites::testing::Public, Error};
+        use ark_std::string::ToString;
+
+        assert_eq!(Error::InvalidPublicKey.to_string(), "invalid public key");
+        let err = Error::RingTooLarge {
+            expected: 10,
+            actual: 11,
+        };
+        assert_eq!(err.to_string(), "ring too large: max size 10, got 11");
+        assert_eq!(
+            Public::from_bytes(&[0xff; 3]).unwrap_err(),
+            Error::InvalidEncoding
+        );
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn constant_time_eq() {
+        use subtle::ConstantTimeEq;
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let other = Secret::from_seed(b"other");
+        assert!(bool::from(secret.ct_eq(&secret.clone())));
+        assert!(!bool::from(secret.ct_eq(&other)));
+        assert_ne!(secret, other);
+
+        let input = Input::new(b"foo").unwrap();
+        let output = secret.output(input);
+        assert!(bool::from(output.ct_eq(&secret.output(input))));
+        assert!(!bool::from(output.ct_eq(&other.output(input))));
+        assert!(bool::from(
+            output.hash()[..].ct_eq(&secret.output(input).hash()[..])
+        ));
+    }
+
+    #[test]
+    fn public_ord_hash() {
+        use ark_std::collections::BTreeSet;
+        use std::collections::HashSet;
+
+        let mut publics: Vec<_> = (0..10_u8)
+            .map(|i| Secret::from_seed(&[i]).public())
+            .collect();
+        let set: HashSet<_> = publics.iter().copied().collect();
+        assert_eq!(set.len(), publics.len());
+        assert!(set.contains(&Secret::from_seed(&[3]).public()));
+
+        let tree: BTreeSet<_> = publics.iter().copied().collect();
+        publics.sort();
+        assert!(tree.into_iter().eq(publics.iter().copied()));
+        assert!(publics.windows(2).all(|w| w[0].to_bytes() < w[1].to_bytes()));
+    }
+
+    #[test]
+    fn keypair_works() {
+        use crate::suites::testing::TestSuite;
+        type Keypair = crate::Keypair<TestSuite>;
+
+        let keypair = Keypair::from_seed(TEST_SEED);
+        let secret = Secret::from_seed(TEST_SEED);
+        assert_eq!(keypair.public(), secret.public());
+        assert_eq!(keypair.secret(), &secret);
+        assert_eq!(Keypair::from(secret), keypair);
+
+        #[cfg(feature = "getrandom")]
+        {
+            let keypair = Keypair::generate();
+            assert_eq!(keypair.public(), keypair.secret().public());
+            assert_ne!(Keypair::generate(), keypair);
+        }
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn output_expand() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let output = secret.output(Input::new(b"foo").unwrap());
+        let out = output.expand::<sha3::Shake128>(200);
+        assert_eq!(out.len(), 200);
+        assert_eq!(output.expand::<sha3::Shake128>(32), out[..32]);
+        let other = secret.output(Input::new(b"bar").unwrap());
+        assert_ne!(other.expand::<sha3::Shake128>(32), out[..32]);
+    }
+
+    #[test]
+    fn input_hasher_chunks() {
+        use crate::{suites::testing::TestSuite, InputHasher};
+        use ark_std::io::Write;
+
+        let data = [0x42; 1000];
+        let input = InputHasher::<TestSuite>::new()
+            .chain_update(&data[..])
+            .finalize()
+            .unwrap();
+
+        let mut hasher = InputHasher::<TestSuite>::new();
+        data.chunks(7).for_each(|chunk| hasher.update(chunk));
+        assert_eq!(hasher.finalize().unwrap(), input);
+
+        let mut hasher = InputHasher::<TestSuite>::default();
+        hasher.write_all(&data[..]).unwrap();
+        assert_eq!(hasher.finalize().unwrap(), input);
+
+        // Same input as the one constructed over the whole data.
+        assert_eq!(Input::new(&data[..]).unwrap(), input);
+    }
 }
@@ -1,14 +1,38 @@
-use crate::ietf::IetfSuite;
+use crate::ietf::{FixedBaseTable, IetfSuite};
 use crate::*;
 
+/// Seed used to derive the blinding base via `PedersenSuite::derive_blinding_base`.
+pub const BLINDING_BASE_SEED: &[u8] = b"pedersen_blinding_base";
+
 pub trait PedersenSuite: IetfSuite {
     /// Blinding base.
+    ///
+    /// New suites are expected to set this to the value returned by
+    /// `derive_blinding_base`.
+    ///
+    /// Suites predating `derive_blinding_base` retain their original (legacy) constant,
+    /// as changing it would invalidate the published test vectors.
     const BLINDING_BASE: AffinePoint<Self>;
 
+    /// Derive the blinding base using a "nothing-up-my-sleeve" procedure.
+    ///
+    /// The base is obtained by hashing `BLINDING_BASE_SEED` to the curve via
+    /// `Suite::data_to_point`, which is domain separated by `Suite::SUITE_ID`.
+    /// Thus nobody knows the discrete logarithm of the base with respect to the
+    /// suite generator.
+    fn derive_blinding_base() -> Option<AffinePoint<Self>> {
+        Self::data_to_point(BLINDING_BASE_SEED)
+    }
+
     /// Pedersen blinding factor.
     ///
     /// Default implementation is deterministic and loosely inspired by the RFC-9381
     /// challenge procedure. All parameters but `secret` are public.
+    ///
+    /// As for RFC-6979 nonces, the blinding factor is derived from the secret key,
+    /// the input and the additional data. No fresh randomness is required, thus
+    /// proofs are reproducible (e.g. HSMs). The same blinding factor is obtained only
+    /// when proving the same input and additional data, which produces the same proof.
     fn blinding(
         secret: &ScalarField<Self>,
         input: &AffinePoint<Self>,
@@ -26,7 +50,7 @@ pub trait PedersenSuite: IetfSuite {
     }
 }
 
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<S: PedersenSuite> {
     pk_com: AffinePoint<S>,
     r: AffinePoint<S>,
@@ -35,23 +59,408 @@ pub struct Proof<S: PedersenSuite> {
     sb: ScalarField<S>,
 }
 
+utils::bytes_encoding_impl!(
+    Proof<S: PedersenSuite>,
+    Proof::to_bytes,
+    Proof::from_bytes,
+    Proof::<S>::encoded_len
+);
+
 impl<S: PedersenSuite> Proof<S> {
     /// Get public key commitment from proof.
     pub fn key_commitment(&self) -> AffinePoint<S> {
         self.pk_com
     }
+
+    /// Length of the proof encoded via `to_bytes`.
+    ///
+    /// Three points plus two scalars, encoded using the suite codec.
+    pub fn encoded_len() -> usize {
+        let point_len = codec::point_encode::<S>(&S::generator()).len();
+        let scalar_len = codec::scalar_encode::<S>(&ScalarField::<S>::from(1)).len();
+        3 * point_len + 2 * scalar_len
+    }
+
+    /// Encode the proof using the suite codec.
+    ///
+    /// Fixed size layout (see `encoded_len`): `pk_com || r || ok || s || sb`.
+    /// Each point and scalar is encoded via `Suite::Codec`, thus the encoding is
+    /// suite dependent (e.g. endianness and point compression flags).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::encoded_len());
+        for pt in [&self.pk_com, &self.r, &self.ok] {
+            S::Codec::point_encode(pt, &mut buf);
+        }
+        for sc in [&self.s, &self.sb] {
+            S::Codec::scalar_encode(sc, &mut buf);
+        }
+        buf
+    }
+
+    /// Decode a proof previously encoded via `to_bytes`.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` length is not `encoded_len()`,
+    /// if any of the points is not valid or not in the prime order subgroup,
+    /// or if any of the elements is not canonically encoded.
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        Self::decode(
+            buf,
+            codec::point_decode_strict::<S>,
+            codec::scalar_decode_strict::<S>,
+        )
+    }
+
+    /// Decode a proof without canonicity and subgroup checks.
+    ///
+    /// Scalars are reduced modulo the group order. Use `from_bytes` unless
+    /// the encoding comes from a trusted source.
+    pub fn from_bytes_unchecked(buf: &[u8]) -> Result<Self, Error> {
+        Self::decode(buf, codec::point_decode::<S>, |b| {
+            Ok(codec::scalar_decode::<S>(b))
+        })
+    }
+
+    fn decode(
+        buf: &[u8],
+        point_decode: impl Fn(&[u8]) -> Result<AffinePoint<S>, Error>,
+        scalar_decode: impl Fn(&[u8]) -> Result<ScalarField<S>, Error>,
+    ) -> Result<Self, Error> {
+        let point_len = codec::point_encode::<S>(&S::generator()).len();
+        let scalar_len = codec::scalar_encode::<S>(&ScalarField::<S>::from(1)).len();
+        if buf.len() != Self::encoded_len() {
+            return Err(Error::InvalidEncoding);
+        }
+        let (points, scalars) = buf.split_at(3 * point_len);
+        let mut points = points.chunks_exact(point_len).map(point_decode);
+        let mut scalars = scalars.chunks_exact(scalar_len).map(scalar_decode);
+        let mut next_point = || points.next().ok_or(Error::InvalidEncoding)?;
+        let (pk_com, r, ok) = (next_point()?, next_point()?, next_point()?);
+        let mut next_scalar = || scalars.next().ok_or(Error::InvalidEncoding)?;
+        let (s, sb) = (next_scalar()?, next_scalar()?);
+        Ok(Proof {
+            pk_com,
+            r,
+            ok,
+            s,
+            sb,
+        })
+    }
+}
+
+/// Secret Pedersen blinding factor.
+///
+/// Returned when generating a proof and required to open its key commitment
+/// (e.g. by the ring prover). The value is zeroized on drop, is not `Copy` and
+/// is redacted by the `Debug` implementation.
+pub struct SecretBlinding<S: PedersenSuite>(ScalarField<S>);
+
+impl<S: PedersenSuite> SecretBlinding<S> {
+    /// Expose the blinding scalar.
+    ///
+    /// Copies of the returned value are not zeroized.
+    pub fn expose(&self) -> &ScalarField<S> {
+        &self.0
+    }
+
+    /// Blinding factor of a key commitment re-randomized by `delta`.
+    ///
+    /// See `rerandomize_key_commitment`.
+    pub fn rerandomize(&self, delta: &Self) -> Self {
+        Self(self.0 + delta.0)
+    }
+}
+
+impl<S: PedersenSuite> core::fmt::Debug for SecretBlinding<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SecretBlinding(<redacted>)")
+    }
+}
+
+impl<S: PedersenSuite> PartialEq for SecretBlinding<S> {
+    fn eq(&self, other: &Self) -> bool {
+        let a = codec::scalar_encode::<S>(&self.0);
+        let b = codec::scalar_encode::<S>(&other.0);
+        utils::ct_eq(&a, &b)
+    }
+}
+
+impl<S: PedersenSuite> Eq for SecretBlinding<S> {}
+
+#[cfg(feature = "subtle")]
+impl<S: PedersenSuite> subtle::ConstantTimeEq for SecretBlinding<S> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        let a = codec::scalar_encode::<S>(&self.0);
+        let b = codec::scalar_encode::<S>(&other.0);
+        subtle::ConstantTimeEq::ct_eq(&a[..], &b[..])
+    }
+}
+
+#[cfg(feature = "zeroize-secrets")]
+impl<S: PedersenSuite> Zeroize for SecretBlinding<S> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(feature = "zeroize-secrets")]
+impl<S: PedersenSuite> zeroize::ZeroizeOnDrop for SecretBlinding<S> {}
+
+impl<S: PedersenSuite> Drop for SecretBlinding<S> {
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// Pedersen VRF signature.
+///
+/// Bundles the VRF output (pre-output point) with the proof, so that verifiers
+/// can extract the VRF output bytes once the signature has been verified.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<S: PedersenSuite> {
+    output: Output<S>,
+    proof: Proof<S>,
+}
+
+// Hex encoding is `point_to_string(output) || proof.to_bytes()`.
+utils::bytes_encoding_impl!(
+    Signature<S: PedersenSuite>,
+    |sig: &Signature<S>| [codec::point_encode::<S>(&sig.output.0), sig.proof.to_bytes()].concat(),
+    |buf: &[u8]| -> Result<Signature<S>, Error> {
+        let output_len = buf
+            .len()
+            .checked_sub(Proof::<S>::encoded_len())
+            .ok_or(Error::InvalidEncoding)?;
+        let (output, proof) = buf.split_at(output_len);
+        let output = Output(codec::point_decode_strict::<S>(output)?);
+        Ok(Signature::new(output, Proof::from_bytes(proof)?))
+    },
+    || codec::point_encoded_len::<S>() + Proof::<S>::encoded_len()
+);
+
+impl<S: PedersenSuite> Signature<S> {
+    /// Construct from output and proof.
+    pub fn new(output: Output<S>, proof: Proof<S>) -> Self {
+        Self { output, proof }
+    }
+
+    /// VRF output (pre-output point).
+    pub fn output(&self) -> Output<S> {
+        self.output
+    }
+
+    /// VRF output hash, using `Suite::point_to_hash`.
+    ///
+    /// Mirrors RFC-9381 `ECVRF_proof_to_hash`. The hash should be trusted only
+    /// after the signature has been verified.
+    pub fn output_hash(&self) -> HashOutput<S> {
+        self.output.hash()
+    }
+
+    /// Pedersen VRF proof.
+    pub fn proof(&self) -> &Proof<S> {
+        &self.proof
+    }
+
+    /// Verify the signature for the given input and additional data.
+    ///
+    /// On success, returns the VRF output hash.
+    pub fn verify(&self, input: Input<S>, ad: impl AsRef<[u8]>) -> Result<HashOutput<S>, Error> {
+        Public::<S>::verify(input, self.output, ad, &self.proof)?;
+        Ok(self.output_hash())
+    }
+}
+
+impl<S: PedersenSuite> Secret<S> {
+    /// Sign `input` and `ad`, producing the output together with its proof.
+    ///
+    /// The blinding factor is deterministically derived via `PedersenSuite::blinding`.
+    pub fn sign(&self, input: Input<S>, ad: impl AsRef<[u8]>) -> Signature<S> {
+        let output = self.output(input);
+        let (proof, _) = Prover::prove(self, input, output, ad);
+        Signature::new(output, proof)
+    }
+}
+
+/// Pedersen VRF signature over multiple inputs.
+///
+/// The input/output pairs are merged into a single pair via a random linear
+/// combination, with weights derived by hashing all the pairs. A single proof,
+/// with one key commitment, then covers all the outputs.
+#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultiSignature<S: PedersenSuite> {
+    outputs: Vec<Output<S>>,
+    proof: Proof<S>,
+}
+
+impl<S: PedersenSuite> MultiSignature<S> {
+    /// VRF outputs, in the same order as the signed inputs.
+    pub fn outputs(&self) -> &[Output<S>] {
+        &self.outputs
+    }
+
+    /// Pedersen VRF proof covering all the outputs.
+    pub fn proof(&self) -> &Proof<S> {
+        &self.proof
+    }
+
+    /// Verify the signature for the given inputs and additional data.
+    ///
+    /// On success, returns the VRF output hashes.
+    pub fn verify(
+        &self,
+        inputs: &[Input<S>],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<Vec<HashOutput<S>>, Error> {
+        let (input, output) = ietf::merge_ios(inputs, &self.outputs)?;
+        Public::<S>::verify(input, output, ad, &self.proof)?;
+        Ok(self.outputs.iter().map(|o| o.hash()).collect())
+    }
+}
+
+impl<S: PedersenSuite> Secret<S> {
+    /// Sign multiple inputs with a single proof.
+    ///
+    /// Returns `Error::InvalidData` if `inputs` is empty.
+    pub fn sign_many(
+        &self,
+        inputs: &[Input<S>],
+        ad: impl AsRef<[u8]>,
+    ) -> Result<MultiSignature<S>, Error> {
+        let outputs: Vec<_> = inputs.iter().map(|i| self.output(*i)).collect();
+        let (input, output) = ietf::merge_ios(inputs, &outputs)?;
+        let (proof, _) = Prover::prove(self, input, output, ad);
+        Ok(MultiSignature { outputs, proof })
+    }
+}
+
+/// Pedersen VRF parameters.
+///
+/// By default the suite `BLINDING_BASE` is used. Applications can derive their own
+/// blinding base from a domain string via `PedersenParams::from_domain`, so that
+/// unrelated deployments don't share the Pedersen parameters.
+///
+/// Note that the Ring VRF always uses the suite `BLINDING_BASE`, as it is embedded
+/// in the ring proof parameters.
+pub struct PedersenParams<S: PedersenSuite> {
+    blinding_base: AffinePoint<S>,
+}
+
+impl<S: PedersenSuite> core::fmt::Debug for PedersenParams<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PedersenParams")
+            .field("blinding_base", &self.blinding_base)
+            .finish()
+    }
+}
+
+impl<S: PedersenSuite> Clone for PedersenParams<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: PedersenSuite> Copy for PedersenParams<S> {}
+
+impl<S: PedersenSuite> PartialEq for PedersenParams<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.blinding_base == other.blinding_base
+    }
+}
+
+impl<S: PedersenSuite> Eq for PedersenParams<S> {}
+
+impl<S: PedersenSuite> Default for PedersenParams<S> {
+    fn default() -> Self {
+        Self {
+            blinding_base: S::BLINDING_BASE,
+        }
+    }
+}
+
+impl<S: PedersenSuite> PedersenParams<S> {
+    /// Construct parameters with a blinding base derived from the application `domain`.
+    ///
+    /// The base is obtained by hashing `BLINDING_BASE_SEED || domain` to the curve via
+    /// `Suite::data_to_point`, thus its discrete logarithm is unknown.
+    ///
+    /// Returns `None` if the suite hash-to-curve procedure fails.
+    pub fn from_domain(domain: &[u8]) -> Option<Self> {
+        let blinding_base = S::data_to_point(&[BLINDING_BASE_SEED, domain].concat())?;
+        Some(Self { blinding_base })
+    }
+
+    /// Blinding base.
+    pub fn blinding_base(&self) -> AffinePoint<S> {
+        self.blinding_base
+    }
 }
 
 pub trait Prover<S: PedersenSuite> {
     /// Generate a proof for the given input/output and user additional data.
     ///
+    /// The blinding factor is deterministically derived via `PedersenSuite::blinding`.
+    ///
     /// Returns the proof together with the associated blinding factor.
     fn prove(
         &self,
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-    ) -> (Proof<S>, ScalarField<S>);
+    ) -> (Proof<S>, SecretBlinding<S>) {
+        self.prove_with_params(&PedersenParams::default(), input, output, ad)
+    }
+
+    /// Generate a proof using the given Pedersen parameters.
+    ///
+    /// Returns the proof together with the associated blinding factor.
+    fn prove_with_params(
+        &self,
+        params: &PedersenParams<S>,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> (Proof<S>, SecretBlinding<S>);
+
+    /// Generate a proof and check it before returning it.
+    ///
+    /// Fault attack countermeasure: the proof is verified and its key commitment
+    /// is checked to open to the signer public key with the returned blinding.
+    ///
+    /// Returns `Error::VerificationFailure` if any of the checks fails, in which
+    /// case nothing should be disclosed.
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(Proof<S>, SecretBlinding<S>), Error>;
+
+    /// Generate a proof sampling the blinding factor from `rng`.
+    ///
+    /// Returns the proof together with the associated blinding factor.
+    fn prove_with_rng(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> (Proof<S>, SecretBlinding<S>);
+
+    /// Generate a proof in hedged mode.
+    ///
+    /// The deterministic blinding factor and nonces are mixed with auxiliary
+    /// randomness drawn from `rng`. The proof remains secure if either the
+    /// randomness source is weak (as the deterministic procedure) or the
+    /// deterministic derivation is subject to fault attacks (as random sampling).
+    ///
+    /// Returns the proof together with the associated blinding factor.
+    fn prove_hedged(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> (Proof<S>, SecretBlinding<S>);
 }
 
 pub trait Verifier<S: PedersenSuite> {
@@ -64,80 +473,560 @@ pub trait Verifier<S: PedersenSuite> {
         output: Output<S>,
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        Self::verify_with_params(&PedersenParams::default(), input, output, ad, proof)
+    }
+
+    /// Verify a batch of proofs.
+    ///
+    /// Each item is a tuple `(input, output, ad, proof)`.
+    ///
+    /// The verification equations of all the proofs are combined using random
+    /// weights into a single multi-scalar multiplication. The weights are derived
+    /// by hashing the whole batch, thus no randomness source is required.
+    ///
+    /// Returns `Error::VerificationFailure` if any of the proofs is not valid,
+    /// without telling which one.
+    fn verify_batch(items: &[(Input<S>, Output<S>, &[u8], &Proof<S>)]) -> Result<(), Error> {
+        Self::verify_batch_with_params(&PedersenParams::default(), items)
+    }
+
+    /// Verify a batch of proofs using the given Pedersen parameters.
+    ///
+    /// See `Verifier::verify_batch`.
+    fn verify_batch_with_params(
+        params: &PedersenParams<S>,
+        items: &[(Input<S>, Output<S>, &[u8], &Proof<S>)],
+    ) -> Result<(), Error> {
+        use ark_ec::VariableBaseMSM;
+        use ark_ff::Zero;
+
+        const DOM_SEP: u8 = 0xBA;
+
+        // Batch seed: hash of the whole batch.
+        let mut buf = [S::SUITE_ID, &[DOM_SEP]].concat();
+        for (input, output, ad, proof) in items {
+            for pt in [&input.0, &output.0, &proof.pk_com, &proof.r, &proof.ok] {
+                S::Codec::point_encode(pt, &mut buf);
+            }
+            S::Codec::scalar_encode(&proof.s, &mut buf);
+            S::Codec::scalar_encode(&proof.sb, &mut buf);
+            buf.extend_from_slice(&(ad.len() as u64).to_le_bytes());
+            buf.extend_from_slice(ad);
+        }
+        let seed = utils::hash::<S::Hasher>(&buf);
+        let weight = |i: usize| {
+            let hash = utils::hash::<S::Hasher>(&[&seed[..], &(i as u64).to_le_bytes()].concat());
+            S::scalar_from_le_bytes_mod_order(&hash[..16])
+        };
+
+        let mut bases = Vec::with_capacity(6 * items.len() + 2);
+        let mut scalars = Vec::with_capacity(6 * items.len() + 2);
+        let mut g_scalar = ScalarField::<S>::zero();
+        let mut b_scalar = ScalarField::<S>::zero();
+        for (i, (input, output, ad, proof)) in items.iter().enumerate() {
+            let c = S::challenge(
+                &[&proof.pk_com, &input.0, &output.0, &proof.r, &proof.ok],
+                ad,
+            );
+            let (w1, w2) = (weight(2 * i), weight(2 * i + 1));
+            // w1 * (Ok + c*O - s*I)
+            bases.extend_from_slice(&[proof.ok, output.0, input.0]);
+            scalars.extend_from_slice(&[w1, w1 * c, -(w1 * proof.s)]);
+            // w2 * (R + c*Yb - s*G - sb*B)
+            bases.extend_from_slice(&[proof.r, proof.pk_com]);
+            scalars.extend_from_slice(&[w2, w2 * c]);
+            g_scalar -= w2 * proof.s;
+            b_scalar -= w2 * proof.sb;
+        }
+        bases.extend_from_slice(&[S::generator(), params.blinding_base]);
+        scalars.extend_from_slice(&[g_scalar, b_scalar]);
+
+        let res = <AffinePoint<S> as AffineRepr>::Group::msm(&bases, &scalars)
+            .map_err(|_| Error::InvalidData)?;
+        if !res.is_zero() {
+            return Err(Error::VerificationFailure);
+        }
+        Ok(())
+    }
+
+    /// Verify a proof using the given Pedersen parameters.
+    fn verify_with_params(
+        params: &PedersenParams<S>,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
     ) -> Result<(), Error>;
 }
 
-impl<S: PedersenSuite> Prover<S> for Secret<S> {
-    fn prove(
+impl<S: PedersenSuite, T: VrfSigner<S> + ?Sized> Prover<S> for T {
+    fn prove_with_params(
         &self,
+        params: &PedersenParams<S>,
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-    ) -> (Proof<S>, ScalarField<S>) {
+    ) -> (Proof<S>, SecretBlinding<S>) {
         // Build blinding factor
-        let blinding = S::blinding(&self.scalar, &input.0, ad.as_ref());
+        let blinding = self.blinding(input, ad.as_ref(), None);
+        let proof = self.prove_pedersen(params, input, output, ad.as_ref(), blinding, None);
+        (proof, SecretBlinding(blinding))
+    }
 
-        // Construct the nonces
-        let k = S::nonce(&self.scalar, input);
-        let kb = S::nonce(&blinding, input);
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<(Proof<S>, SecretBlinding<S>), Error> {
+        let params = PedersenParams::default();
+        let (proof, blinding) = self.prove_with_params(&params, input, output, ad.as_ref());
+        <Public<S> as Verifier<S>>::verify_with_params(&params, input, output, ad, &proof)?;
+        let pk_com = VrfSigner::public(self).0 + params.blinding_base() * blinding.expose();
+        if proof.key_commitment() != pk_com.into_affine() {
+            return Err(Error::VerificationFailure);
+        }
+        Ok((proof, blinding))
+    }
 
-        // Yb = x*G + b*B
-        let pk_com = (S::generator() * self.scalar + S::BLINDING_BASE * blinding).into_affine();
+    fn prove_with_rng(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> (Proof<S>, SecretBlinding<S>) {
+        use ark_std::UniformRand;
+        let blinding = ScalarField::<S>::rand(rng);
+        let params = PedersenParams::default();
+        let proof = self.prove_pedersen(&params, input, output, ad.as_ref(), blinding, None);
+        (proof, SecretBlinding(blinding))
+    }
 
-        // R = k*G + kb*B
-        let r = (S::generator() * k + S::BLINDING_BASE * kb).into_affine();
-        // Ok = k*I
-        let ok = (input.0 * k).into_affine();
+    fn prove_hedged(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> (Proof<S>, SecretBlinding<S>) {
+        let mut aux = [0; 32];
+        rng.fill_bytes(&mut aux);
+        let blinding = self.blinding(input, ad.as_ref(), Some(&aux));
+        let params = PedersenParams::default();
+        let proof =
+            self.prove_pedersen(&params, input, output, ad.as_ref(), blinding, Some(&aux));
+        (proof, SecretBlinding(blinding))
+    }
+}
 
-        // c = Hash(Yb, I, O, R, Ok, ad)
-        let c = S::challenge(&[&pk_com, &input.0, &output.0, &r, &ok], ad.as_ref());
+/// Mix a deterministically derived secret scalar with auxiliary randomness.
+fn hedge<S: PedersenSuite>(scalar: &ScalarField<S>, aux: &[u8]) -> ScalarField<S> {
+    const DOM_SEP_START: u8 = 0xCD;
+    const DOM_SEP_END: u8 = 0x00;
+    let mut buf = [S::SUITE_ID, &[DOM_SEP_START]].concat();
+    S::Codec::scalar_encode(scalar, &mut buf);
+    buf.extend_from_slice(aux);
+    buf.push(DOM_SEP_END);
+    let hash = &utils::hash::<S::Hasher>(&buf);
+    ScalarField::<S>::from_be_bytes_mod_order(hash)
+}
 
-        // s = k + c*x
-        let s = k + c * self.scalar;
-        // sb = kb + c*b
-        let sb = kb + c * blinding;
+/// Blinding factor for `secret`, optionally hedged with `aux` randomness.
+pub(crate) fn blinding<S: PedersenSuite>(
+    secret: &Secret<S>,
+    input: Input<S>,
+    ad: &[u8],
+    aux: Option<&[u8]>,
+) -> ScalarField<S> {
+    let blinding = S::blinding(&secret.scalar, &input.0, ad);
+    match aux {
+        Some(aux) => hedge::<S>(&blinding, aux),
+        None => blinding,
+    }
+}
 
-        let proof = Proof {
-            pk_com,
-            r,
-            ok,
-            s,
-            sb,
-        };
-        (proof, blinding)
+/// Generate a proof using the given blinding factor.
+///
+/// Nonces are optionally hedged with `aux` randomness.
+pub(crate) fn prove_with_blinding<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    secret: &Secret<S>,
+    input: Input<S>,
+    output: Output<S>,
+    ad: &[u8],
+    blinding: ScalarField<S>,
+    aux: Option<&[u8]>,
+) -> Proof<S> {
+    // Construct the nonces
+    let mut k = S::nonce(&secret.scalar, input);
+    let mut kb = S::nonce(&blinding, input);
+    if let Some(aux) = aux {
+        k = hedge::<S>(&k, aux);
+        kb = hedge::<S>(&kb, aux);
+    }
+    prove_with_nonces(params, secret, input, output, ad, blinding, k, kb)
+}
+
+/// Generate a proof using the given blinding factor and nonces.
+#[allow(clippy::too_many_arguments)]
+fn prove_with_nonces<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    secret: &Secret<S>,
+    input: Input<S>,
+    output: Output<S>,
+    ad: &[u8],
+    blinding: ScalarField<S>,
+    k: ScalarField<S>,
+    kb: ScalarField<S>,
+) -> Proof<S> {
+    let blinding_base = params.blinding_base;
+
+    // Yb = x*G + b*B
+    let pk_com = (S::generator() * secret.scalar + blinding_base * blinding).into_affine();
+
+    // R = k*G + kb*B
+    let r = (S::generator() * k + blinding_base * kb).into_affine();
+    // Ok = k*I
+    let ok = (input.0 * k).into_affine();
+
+    // c = Hash(Yb, I, O, R, Ok, ad)
+    let c = S::challenge(&[&pk_com, &input.0, &output.0, &r, &ok], ad);
+
+    // s = k + c*x
+    let s = k + c * secret.scalar;
+    // sb = kb + c*b
+    let sb = kb + c * blinding;
+
+    Proof {
+        pk_com,
+        r,
+        ok,
+        s,
+        sb,
     }
 }
 
 impl<S: PedersenSuite> Verifier<S> for Public<S> {
-    fn verify(
+    fn verify_with_params(
+        params: &PedersenParams<S>,
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
         proof: &Proof<S>,
     ) -> Result<(), Error> {
-        let Proof {
-            pk_com,
-            r,
-            ok,
-            s,
-            sb,
-        } = proof;
+        verify_with(
+            input,
+            output,
+            ad.as_ref(),
+            proof,
+            |s| S::generator() * s,
+            |sb| params.blinding_base * sb,
+        )
+    }
+}
 
-        // c = Hash(Yb, I, O, R, Ok, ad)
-        let c = S::challenge(&[pk_com, &input.0, &output.0, r, ok], ad.as_ref());
+/// Verify a proof using the given fixed-base multiplications by `G` and `B`.
+fn verify_with<S: PedersenSuite>(
+    input: Input<S>,
+    output: Output<S>,
+    ad: &[u8],
+    proof: &Proof<S>,
+    g_mul: impl Fn(&ScalarField<S>) -> Group<S>,
+    b_mul: impl Fn(&ScalarField<S>) -> Group<S>,
+) -> Result<(), Error> {
+    let Proof {
+        pk_com,
+        r,
+        ok,
+        s,
+        sb,
+    } = proof;
 
-        // Ok + c*O = s*I
-        if output.0 * c + ok != input.0 * s {
-            return Err(Error::VerificationFailure);
+    // c = Hash(Yb, I, O, R, Ok, ad)
+    let c = S::challenge(&[pk_com, &input.0, &output.0, r, ok], ad);
+
+    // Ok + c*O = s*I
+    if output.0 * c + ok != input.0 * s {
+        return Err(Error::VerificationFailure);
+    }
+
+    // R + c*Yb = s*G + sb*B
+    if *pk_com * c + r != g_mul(s) + b_mul(sb) {
+        return Err(Error::VerificationFailure);
+    }
+
+    Ok(())
+}
+
+type Group<S> = <AffinePoint<S> as AffineRepr>::Group;
+
+/// Pedersen verifier precomputation.
+///
+/// Holds windowed multiples tables for the fixed bases (i.e. the suite generator
+/// and the blinding base), built once and then reused by each verification.
+/// Useful to verify many proofs using the same Pedersen parameters.
+///
+/// Verification is not constant time, which is fine as all the involved data is public.
+pub struct PedersenVerifierPrecomp<S: PedersenSuite> {
+    params: PedersenParams<S>,
+    g_table: FixedBaseTable<S>,
+    b_table: FixedBaseTable<S>,
+}
+
+impl<S: PedersenSuite> Default for PedersenVerifierPrecomp<S> {
+    fn default() -> Self {
+        Self::new(PedersenParams::default())
+    }
+}
+
+impl<S: PedersenSuite> PedersenVerifierPrecomp<S> {
+    /// Build the tables for the given Pedersen parameters.
+    pub fn new(params: PedersenParams<S>) -> Self {
+        Self {
+            g_table: FixedBaseTable::new(S::generator()),
+            b_table: FixedBaseTable::new(params.blinding_base),
+            params,
         }
+    }
 
-        // R + c*Yb = s*G + sb*B
-        if *pk_com * c + r != S::generator() * s + S::BLINDING_BASE * sb {
-            return Err(Error::VerificationFailure);
+    /// Pedersen parameters the tables have been built for.
+    pub fn params(&self) -> &PedersenParams<S> {
+        &self.params
+    }
+
+    /// Verify a proof for the given input/output and user additional data.
+    ///
+    /// Equivalent to `Verifier::verify_with_params` using `self.params()`.
+    pub fn verify(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &Proof<S>,
+    ) -> Result<(), Error> {
+        verify_with(
+            input,
+            output,
+            ad.as_ref(),
+            proof,
+            |s| self.g_table.mul(s),
+            |sb| self.b_table.mul(sb),
+        )
+    }
+}
+
+/// Key commitment opening proof.
+///
+/// Proves that a Pedersen proof key commitment `Yb` commits to a given public key `Y`,
+/// i.e. knowledge of the blinding factor `b` such that `Yb - Y = b*B`.
+///
+/// Allows a signer to selectively reveal the authorship of a (ring) proof.
+#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OpeningProof<S: PedersenSuite> {
+    r: AffinePoint<S>,
+    s: ScalarField<S>,
+}
+
+/// Additional data domain separator for key commitment opening proofs.
+const OPENING_DOM: &[u8] = b"pedersen_key_commitment_opening";
+
+/// Additional data domain separator for key commitment re-randomization proofs.
+const RERANDOMIZATION_DOM: &[u8] = b"pedersen_key_commitment_rerandomization";
+
+impl<S: PedersenSuite> Secret<S> {
+    /// Open the key commitment carried by `proof`.
+    ///
+    /// `blinding` is the blinding factor returned when generating `proof`, while
+    /// `ad` is additional data bound to the opening (e.g. a verifier challenge).
+    ///
+    /// Returns `Error::InvalidData` if `proof` key commitment doesn't commit to this
+    /// secret public key using `blinding`.
+    pub fn open_key_commitment(
+        &self,
+        proof: &Proof<S>,
+        blinding: &SecretBlinding<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<OpeningProof<S>, Error> {
+        self.open_key_commitment_with_params(&PedersenParams::default(), proof, blinding, ad)
+    }
+
+    /// Open the key commitment carried by a `proof` generated using the given
+    /// Pedersen parameters.
+    ///
+    /// See `Secret::open_key_commitment`.
+    pub fn open_key_commitment_with_params(
+        &self,
+        params: &PedersenParams<S>,
+        proof: &Proof<S>,
+        blinding: &SecretBlinding<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> Result<OpeningProof<S>, Error> {
+        let blinding = blinding.0;
+        let pk = self.public.0;
+        let pk_com = proof.pk_com;
+        if pk_com != (pk + params.blinding_base * blinding).into_affine() {
+            return Err(Error::InvalidData);
         }
+        Ok(prove_blinding_dlog(
+            params,
+            &pk,
+            &pk_com,
+            blinding,
+            OPENING_DOM,
+            ad.as_ref(),
+        ))
+    }
+}
 
-        Ok(())
+/// Verify that `key_commitment` commits to `public` using the `opening` proof.
+pub fn verify_key_commitment_opening<S: PedersenSuite>(
+    public: &Public<S>,
+    key_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    opening: &OpeningProof<S>,
+) -> Result<(), Error> {
+    verify_key_commitment_opening_with_params(
+        &PedersenParams::default(),
+        public,
+        key_commitment,
+        ad,
+        opening,
+    )
+}
+
+/// Verify that `key_commitment` commits to `public` using the `opening` proof and
+/// the given Pedersen parameters.
+pub fn verify_key_commitment_opening_with_params<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    public: &Public<S>,
+    key_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    opening: &OpeningProof<S>,
+) -> Result<(), Error> {
+    verify_blinding_dlog(
+        params,
+        &public.0,
+        key_commitment,
+        OPENING_DOM,
+        ad.as_ref(),
+        opening,
+    )
+}
+
+/// Re-randomize a key commitment with a fresh blinding factor drawn from `rng`.
+///
+/// Returns the new key commitment `Yb' = Yb + d*B`, the blinding delta `d` and
+/// a proof that `Yb'` commits to the same public key as `Yb`. If `b` is the
+/// blinding factor of `Yb`, then the blinding factor of `Yb'` is `b + d`
+/// (see `SecretBlinding::rerandomize`).
+///
+/// Allows to present the same key commitment in unlinkable form to different
+/// contexts without running the full signing procedure again.
+pub fn rerandomize_key_commitment<S: PedersenSuite>(
+    key_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+) -> (AffinePoint<S>, SecretBlinding<S>, OpeningProof<S>) {
+    rerandomize_key_commitment_with_params(&PedersenParams::default(), key_commitment, ad, rng)
+}
+
+/// Re-randomize a key commitment generated using the given Pedersen parameters.
+///
+/// See `rerandomize_key_commitment`.
+pub fn rerandomize_key_commitment_with_params<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    key_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+) -> (AffinePoint<S>, SecretBlinding<S>, OpeningProof<S>) {
+    use ark_std::UniformRand;
+    let delta = ScalarField::<S>::rand(rng);
+    let new_commitment = (*key_commitment + params.blinding_base * delta).into_affine();
+    let proof = prove_blinding_dlog(
+        params,
+        key_commitment,
+        &new_commitment,
+        delta,
+        RERANDOMIZATION_DOM,
+        ad.as_ref(),
+    );
+    (new_commitment, SecretBlinding(delta), proof)
+}
+
+/// Verify that `new_commitment` is a re-randomization of `key_commitment`.
+pub fn verify_key_commitment_rerandomization<S: PedersenSuite>(
+    key_commitment: &AffinePoint<S>,
+    new_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    proof: &OpeningProof<S>,
+) -> Result<(), Error> {
+    verify_key_commitment_rerandomization_with_params(
+        &PedersenParams::default(),
+        key_commitment,
+        new_commitment,
+        ad,
+        proof,
+    )
+}
+
+/// Verify that `new_commitment` is a re-randomization of `key_commitment` using
+/// the given Pedersen parameters.
+pub fn verify_key_commitment_rerandomization_with_params<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    key_commitment: &AffinePoint<S>,
+    new_commitment: &AffinePoint<S>,
+    ad: impl AsRef<[u8]>,
+    proof: &OpeningProof<S>,
+) -> Result<(), Error> {
+    verify_blinding_dlog(
+        params,
+        key_commitment,
+        new_commitment,
+        RERANDOMIZATION_DOM,
+        ad.as_ref(),
+        proof,
+    )
+}
+
+/// Prove knowledge of `d` such that `to - from = d*B`.
+fn prove_blinding_dlog<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    from: &AffinePoint<S>,
+    to: &AffinePoint<S>,
+    d: ScalarField<S>,
+    dom: &[u8],
+    ad: &[u8],
+) -> OpeningProof<S> {
+    // k = nonce(d, to)
+    let k = S::nonce(&d, Input(*to));
+    // R = k*B
+    let r = (params.blinding_base * k).into_affine();
+    // c = Hash(from, to, R, ad)
+    let ad = [dom, ad].concat();
+    let c = S::challenge(&[from, to, &r], &ad);
+    // s = k + c*d
+    let s = k + c * d;
+    OpeningProof { r, s }
+}
+
+/// Verify a proof of knowledge of `d` such that `to - from = d*B`.
+fn verify_blinding_dlog<S: PedersenSuite>(
+    params: &PedersenParams<S>,
+    from: &AffinePoint<S>,
+    to: &AffinePoint<S>,
+    dom: &[u8],
+    ad: &[u8],
+    proof: &OpeningProof<S>,
+) -> Result<(), Error> {
+    let ad = [dom, ad].concat();
+    let c = S::challenge(&[from, to, &proof.r], &ad);
+    // s*B = R + c*(to - from)
+    if params.blinding_base * proof.s != proof.r + (*to - *from) * c {
+        return Err(Error::VerificationFailure);
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -145,6 +1034,11 @@ pub(crate) mod testing {
     use super::*;
     use crate::testing::{self as common, random_val, TEST_SEED};
 
+    /// Application specific parameters shared by the tests.
+    pub fn app_params<S: PedersenSuite>() -> PedersenParams<S> {
+        PedersenParams::from_domain(b"app").unwrap()
+    }
+
     pub fn prove_verify<S: PedersenSuite>() {
         use pedersen::{Prover, Verifier};
 
@@ -158,8 +1052,395 @@ pub(crate) mod testing {
 
         assert_eq!(
             proof.key_commitment(),
-            (secret.public().0 + S::BLINDING_BASE * blinding).into()
+            (secret.public().0 + S::BLINDING_BASE * blinding.expose()).into()
+        );
+
+        let (checked, checked_blinding) = secret.prove_checked(input, output, b"foo").unwrap();
+        assert_eq!(checked.to_bytes(), proof.to_bytes());
+        assert_eq!(checked_blinding, blinding);
+        let other = Secret::<S>::from_seed(b"other").output(input);
+        assert_eq!(
+            secret.prove_checked(input, other, b"foo").unwrap_err(),
+            Error::VerificationFailure
+        );
+    }
+
+    pub fn prove_with_rng<S: PedersenSuite>() {
+        use ark_std::rand::SeedableRng;
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+        let (proof, blinding1) = secret.prove_with_rng(input, output, b"foo", &mut rng);
+        let result = Public::verify(input, output, b"foo", &proof);
+        assert!(result.is_ok());
+        assert_eq!(
+            proof.key_commitment(),
+            (secret.public().0 + S::BLINDING_BASE * blinding1.expose()).into()
+        );
+
+        // Fresh blinding from the rng.
+        let (_, blinding2) = secret.prove_with_rng(input, output, b"foo", &mut rng);
+        assert_ne!(blinding1, blinding2);
+
+        // Reproducible with the same rng seed.
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+        let (_, blinding3) = secret.prove_with_rng(input, output, b"foo", &mut rng);
+        assert_eq!(blinding1, blinding3);
+    }
+
+    pub fn prove_hedged<S: PedersenSuite>() {
+        use ark_std::rand::SeedableRng;
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+        let (proof1, blinding1) = secret.prove_hedged(input, output, b"foo", &mut rng);
+        assert!(Public::verify(input, output, b"foo", &proof1).is_ok());
+        assert_eq!(
+            proof1.key_commitment(),
+            (secret.public().0 + S::BLINDING_BASE * blinding1.expose()).into()
+        );
+
+        // Fresh auxiliary randomness gives a different proof.
+        let (proof2, blinding2) = secret.prove_hedged(input, output, b"foo", &mut rng);
+        assert!(Public::verify(input, output, b"foo", &proof2).is_ok());
+        assert_ne!(blinding1, blinding2);
+        assert_ne!(proof1.to_bytes(), proof2.to_bytes());
+
+        // Differs from the deterministic mode.
+        let (_, blinding3) = secret.prove(input, output, b"foo");
+        assert_ne!(blinding1, blinding3);
+    }
+
+    pub fn proof_encoding<S: PedersenSuite>() {
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+        let (proof, _) = secret.prove(input, output, b"foo");
+
+        let buf = proof.to_bytes();
+        assert_eq!(buf.len(), Proof::<S>::encoded_len());
+        let decoded = Proof::<S>::from_bytes(&buf).unwrap();
+        assert_eq!(decoded.to_bytes(), buf);
+        assert!(Public::verify(input, output, b"foo", &decoded).is_ok());
+
+        assert!(Proof::<S>::from_bytes(&buf[1..]).is_err());
+        assert!(Proof::<S>::from_bytes(&[buf.clone(), vec![0]].concat()).is_err());
+
+        // Non canonical `sb` is only accepted by the unchecked decoder.
+        let mut bad = buf.clone();
+        let len = bad.len();
+        let scalar_len = codec::scalar_encode::<S>(&proof.sb).len();
+        bad[len - scalar_len..].fill(0xff);
+        assert!(Proof::<S>::from_bytes(&bad).is_err());
+        assert!(Proof::<S>::from_bytes_unchecked(&bad).is_ok());
+    }
+
+    pub fn verify_batch<S: PedersenSuite>() {
+        use pedersen::{Prover, Verifier};
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let ads: [&[u8]; 3] = [b"foo", b"bar", b""];
+        let items: Vec<_> = ads
+            .iter()
+            .map(|ad| {
+                let input = Input::<S>::from(random_val(None));
+                let output = secret.output(input);
+                let (proof, _) = secret.prove(input, output, ad);
+                (input, output, *ad, proof)
+            })
+            .collect();
+        let mut batch: Vec<_> = items
+            .iter()
+            .map(|(input, output, ad, proof)| (*input, *output, *ad, proof))
+            .collect();
+
+        assert!(Public::<S>::verify_batch(&[]).is_ok());
+        assert!(Public::verify_batch(&batch).is_ok());
+
+        // Proofs generated with different Pedersen parameters.
+        let params = app_params::<S>();
+        let input = Input::<S>::from(random_val(None));
+        let output = secret.output(input);
+        let (proof, _) = secret.prove_with_params(&params, input, output, b"foo");
+        let other_batch = [(input, output, &b"foo"[..], &proof)];
+        assert!(Public::verify_batch_with_params(&params, &other_batch).is_ok());
+        assert!(Public::verify_batch(&other_batch).is_err());
+
+        // Tamper with one item
+        batch[1].2 = b"baz";
+        assert!(Public::verify_batch(&batch).is_err());
+    }
+
+    pub fn key_commitment_opening<S: PedersenSuite>() {
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let (proof, blinding) = secret.prove(input, output, b"foo");
+        let opening = secret
+            .open_key_commitment(&proof, &blinding, b"bar")
+            .unwrap();
+        let key_commitment = proof.key_commitment();
+        assert!(verify_key_commitment_opening(&public, &key_commitment, b"bar", &opening).is_ok());
+
+        // Wrong additional data
+        assert!(verify_key_commitment_opening(&public, &key_commitment, b"baz", &opening).is_err());
+
+        // Wrong public key
+        let other = Secret::<S>::from_seed(b"other").public();
+        assert!(verify_key_commitment_opening(&other, &key_commitment, b"bar", &opening).is_err());
+
+        // Wrong blinding
+        let wrong = SecretBlinding(*blinding.expose() + blinding.expose());
+        let res = secret.open_key_commitment(&proof, &wrong, b"bar");
+        assert!(res.is_err());
+
+        // Custom Pedersen parameters
+        let params = app_params::<S>();
+        let (proof, blinding) = secret.prove_with_params(&params, input, output, b"foo");
+        let key_commitment = proof.key_commitment();
+        assert!(secret
+            .open_key_commitment(&proof, &blinding, b"bar")
+            .is_err());
+        let opening = secret
+            .open_key_commitment_with_params(&params, &proof, &blinding, b"bar")
+            .unwrap();
+        let res = verify_key_commitment_opening_with_params(
+            &params,
+            &public,
+            &key_commitment,
+            b"bar",
+            &opening,
+        );
+        assert!(res.is_ok());
+        assert!(verify_key_commitment_opening(&public, &key_commitment, b"bar", &opening).is_err());
+    }
+
+    pub fn key_commitment_rerandomization<S: PedersenSuite>() {
+        use ark_std::rand::SeedableRng;
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+
+        let (proof, blinding) = secret.prove(input, output, b"foo");
+        let key_commitment = proof.key_commitment();
+        let (new_commitment, delta, rerand) =
+            rerandomize_key_commitment(&key_commitment, b"bar", &mut rng);
+        assert_ne!(new_commitment, key_commitment);
+        let res = verify_key_commitment_rerandomization(
+            &key_commitment,
+            &new_commitment,
+            b"bar",
+            &rerand,
+        );
+        assert!(res.is_ok());
+
+        // Wrong additional data
+        let res = verify_key_commitment_rerandomization(
+            &key_commitment,
+            &new_commitment,
+            b"baz",
+            &rerand,
+        );
+        assert!(res.is_err());
+
+        // Unrelated commitment
+        let (other_commitment, _, _) =
+            rerandomize_key_commitment(&key_commitment, b"bar", &mut rng);
+        let res = verify_key_commitment_rerandomization(
+            &key_commitment,
+            &other_commitment,
+            b"bar",
+            &rerand,
+        );
+        assert!(res.is_err());
+
+        // New commitment opens to the same public key using `b + d`.
+        let new_blinding = blinding.rerandomize(&delta);
+        assert_eq!(
+            new_commitment,
+            (public.0 + S::BLINDING_BASE * new_blinding.expose()).into_affine()
+        );
+
+        // Custom Pedersen parameters
+        let params = app_params::<S>();
+        let (proof, blinding) = secret.prove_with_params(&params, input, output, b"foo");
+        let key_commitment = proof.key_commitment();
+        let (new_commitment, delta, rerand) =
+            rerandomize_key_commitment_with_params(&params, &key_commitment, b"bar", &mut rng);
+        let res = verify_key_commitment_rerandomization_with_params(
+            &params,
+            &key_commitment,
+            &new_commitment,
+            b"bar",
+            &rerand,
+        );
+        assert!(res.is_ok());
+        let res = verify_key_commitment_rerandomization(
+            &key_commitment,
+            &new_commitment,
+            b"bar",
+            &rerand,
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            new_commitment,
+            (public.0 + params.blinding_base() * blinding.rerandomize(&delta).expose())
+                .into_affine()
+        );
+    }
+
+    pub fn secret_blinding<S: PedersenSuite>() {
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let (_, blinding) = secret.prove(input, output, b"foo");
+        let dbg = format!("{:?}", blinding);
+        assert_eq!(dbg, "SecretBlinding(<redacted>)");
+        let scalar = codec::scalar_encode::<S>(blinding.expose());
+        assert!(!dbg.contains(&hex::encode(scalar)));
+    }
+
+    pub fn deterministic_blinding<S: PedersenSuite>() {
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let (proof1, blinding1) = secret.prove(input, output, b"foo");
+        let (proof2, blinding2) = secret.prove(input, output, b"foo");
+        assert_eq!(blinding1, blinding2);
+        let mut buf = (Vec::new(), Vec::new());
+        proof1.serialize_compressed(&mut buf.0).unwrap();
+        proof2.serialize_compressed(&mut buf.1).unwrap();
+        assert_eq!(buf.0, buf.1);
+
+        // Blinding depends on the additional data.
+        let (_, blinding3) = secret.prove(input, output, b"bar");
+        assert_ne!(blinding1, blinding3);
+    }
+
+    pub fn prove_verify_with_params<S: PedersenSuite>() {
+        use pedersen::{Prover, Verifier};
+
+        let params = app_params::<S>();
+        assert_ne!(params.blinding_base(), S::BLINDING_BASE);
+        assert_ne!(params, PedersenParams::from_domain(b"other app").unwrap());
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let (proof, blinding) = secret.prove_with_params(&params, input, output, b"foo");
+        let result = Public::verify_with_params(&params, input, output, b"foo", &proof);
+        assert!(result.is_ok());
+
+        assert_eq!(
+            proof.key_commitment(),
+            (secret.public().0 + params.blinding_base() * blinding.expose()).into()
         );
+
+        // Verification with different parameters fails.
+        let result = Public::verify(input, output, b"foo", &proof);
+        assert!(result.is_err());
+    }
+
+    pub fn signature<S: PedersenSuite>() {
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+
+        let signature = secret.sign(input, b"foo");
+        assert_eq!(signature.output(), secret.output(input));
+
+        let hash = signature.verify(input, b"foo").unwrap();
+        assert_eq!(hash, signature.output_hash());
+        assert_eq!(hash, secret.output(input).hash());
+
+        assert!(signature.verify(input, b"bar").is_err());
+        let other = Input::from(random_val(None));
+        assert!(signature.verify(other, b"foo").is_err());
+    }
+
+    pub fn verifier_precomp<S: PedersenSuite>() {
+        use pedersen::Prover;
+
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let input = Input::from(random_val(None));
+        let output = secret.output(input);
+
+        let precomp = PedersenVerifierPrecomp::<S>::default();
+        let (proof, _) = secret.prove(input, output, b"foo");
+        assert!(precomp.verify(input, output, b"foo", &proof).is_ok());
+        assert!(precomp.verify(input, output, b"bar", &proof).is_err());
+
+        let params = app_params::<S>();
+        let precomp = PedersenVerifierPrecomp::new(params);
+        assert_eq!(precomp.params(), &params);
+        assert!(precomp.verify(input, output, b"foo", &proof).is_err());
+        let (proof, _) = secret.prove_with_params(&params, input, output, b"foo");
+        assert!(precomp.verify(input, output, b"foo", &proof).is_ok());
+    }
+
+    pub fn sign_many<S: PedersenSuite>() {
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let inputs: Vec<Input<S>> = (0..3).map(|_| Input::from(random_val(None))).collect();
+
+        let signature = secret.sign_many(&inputs, b"foo").unwrap();
+        let hashes = signature.verify(&inputs, b"foo").unwrap();
+        for ((input, output), hash) in inputs.iter().zip(signature.outputs()).zip(hashes) {
+            assert_eq!(*output, secret.output(*input));
+            assert_eq!(hash, output.hash());
+        }
+
+        assert!(signature.verify(&inputs, b"bar").is_err());
+        assert!(signature.verify(&inputs[..2], b"foo").is_err());
+        let mut swapped = inputs.clone();
+        swapped.swap(0, 1);
+        assert!(signature.verify(&swapped, b"foo").is_err());
+
+        assert!(secret.sign_many(&[], b"foo").is_err());
+    }
+
+    /// Check that the blinding base is a valid prime order subgroup point
+    /// different from the generator.
+    pub fn blinding_base_check<S: PedersenSuite>() {
+        let base = S::BLINDING_BASE;
+        assert!(!base.is_zero());
+        assert_ne!(base, S::generator());
+        assert!(base.mul_bigint(ScalarField::<S>::MODULUS).is_zero());
+        let decoded = codec::point_decode::<S>(&codec::point_encode::<S>(&base)).unwrap();
+        assert_eq!(decoded, base);
+
+        let derived = S::derive_blinding_base().unwrap();
+        assert!(!derived.is_zero());
+        assert_ne!(derived, S::generator());
+    }
+
+    /// Check that the blinding base has been derived via `derive_blinding_base`.
+    #[allow(unused)]
+    pub fn blinding_base_derivation<S: PedersenSuite>() {
+        assert_eq!(S::BLINDING_BASE, S::derive_blinding_base().unwrap());
     }
 
     #[macro_export]
@@ -169,6 +1450,71 @@ pub(crate) mod testing {
             fn pedersen_prove_verify() {
                 $crate::pedersen::testing::prove_verify::<$suite>();
             }
+
+            #[test]
+            fn pedersen_prove_with_rng() {
+                $crate::pedersen::testing::prove_with_rng::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_prove_hedged() {
+                $crate::pedersen::testing::prove_hedged::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_proof_encoding() {
+                $crate::pedersen::testing::proof_encoding::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_signature() {
+                $crate::pedersen::testing::signature::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_sign_many() {
+                $crate::pedersen::testing::sign_many::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_verify_batch() {
+                $crate::pedersen::testing::verify_batch::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_verifier_precomp() {
+                $crate::pedersen::testing::verifier_precomp::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_key_commitment_opening() {
+                $crate::pedersen::testing::key_commitment_opening::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_key_commitment_rerandomization() {
+                $crate::pedersen::testing::key_commitment_rerandomization::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_secret_blinding() {
+                $crate::pedersen::testing::secret_blinding::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_deterministic_blinding() {
+                $crate::pedersen::testing::deterministic_blinding::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_prove_verify_with_params() {
+                $crate::pedersen::testing::prove_verify_with_params::<$suite>();
+            }
+
+            #[test]
+            fn pedersen_blinding_base_check() {
+                $crate::pedersen::testing::blinding_base_check::<$suite>();
+            }
         };
     }
 
@@ -200,6 +1546,7 @@ pub(crate) mod testing {
             let output = Output::from(base.gamma);
             let secret = Secret::from_scalar(base.sk);
             let (proof, blind) = secret.prove(input, output, ad);
+            let blind = *blind.expose();
             Self { base, blind, proof }
         }
 
@@ -261,7 +1608,7 @@ pub(crate) mod testing {
             let output = Output::from(self.base.gamma);
             let sk = Secret::from_scalar(self.base.sk);
             let (proof, blind) = sk.prove(input, output, &self.base.ad);
-            assert_eq!(self.blind, blind, "Blinding factor mismatch");
+            assert_eq!(&self.blind, blind.expose(), "Blinding factor mismatch");
             assert_eq!(self.proof.pk_com, proof.pk_com, "Proof pkb mismatch");
             assert_eq!(self.proof.r, proof.r, "Proof r mismatch");
             assert_eq!(self.proof.ok, proof.ok, "Proof ok mismatch");
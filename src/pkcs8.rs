@@ -0,0 +1,283 @@
+//! PKCS#8 and SPKI key encodings.
+//!
+//! Available for suites over curves with a standard named curve OID, using the
+//! SEC1 codec. Secret keys are encoded as PKCS#8 `PrivateKeyInfo` (RFC-5208)
+//! wrapping a SEC1 `ECPrivateKey` (RFC-5915), public keys as X.509
+//! `SubjectPublicKeyInfo` (RFC-5480) with uncompressed point encoding.
+//! Both DER and PEM (RFC-7468) formats are supported.
+
+use crate::*;
+use ark_std::string::String;
+use zeroize::Zeroizing;
+
+/// Suite with standard PKCS#8 and SPKI key encodings.
+pub trait Pkcs8Suite: Suite<Codec = codec::Sec1Codec> {
+    /// Named curve OID (RFC-5480), DER encoded without tag and length.
+    const CURVE_OID: &'static [u8];
+}
+
+/// `id-ecPublicKey` OID (1.2.840.10045.2.1).
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_CONTEXT_0: u8 = 0xa0;
+const TAG_CONTEXT_1: u8 = 0xa1;
+
+const PEM_PRIVATE_KEY: &str = "PRIVATE KEY";
+const PEM_PUBLIC_KEY: &str = "PUBLIC KEY";
+
+fn der_encode(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut buf = ark_std::vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        buf.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let skip = len_bytes.iter().take_while(|&&b| b == 0).count();
+        buf.push(0x80 | (len_bytes.len() - skip) as u8);
+        buf.extend_from_slice(&len_bytes[skip..]);
+    }
+    buf.extend_from_slice(content);
+    buf
+}
+
+// Reads a DER element with the given tag. Returns its content and the remaining data.
+fn der_decode(buf: &[u8], tag: u8) -> Result<(&[u8], &[u8]), Error> {
+    if buf.len() < 2 || buf[0] != tag {
+        return Err(Error::InvalidEncoding);
+    }
+    let (l, buf) = (buf[1], &buf[2..]);
+    let (len, buf) = if l < 0x80 {
+        (l as usize, buf)
+    } else {
+        let n = (l & 0x7f) as usize;
+        if n == 0 || n > core::mem::size_of::<usize>() || buf.len() < n || buf[0] == 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        let len = buf[..n].iter().fold(0, |acc, &b| (acc << 8) | b as usize);
+        if len < 0x80 {
+            // Non minimal length encoding
+            return Err(Error::InvalidEncoding);
+        }
+        (len, &buf[n..])
+    };
+    if buf.len() < len {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(buf.split_at(len))
+}
+
+fn der_decode_all(buf: &[u8], tag: u8) -> Result<&[u8], Error> {
+    match der_decode(buf, tag)? {
+        (content, []) => Ok(content),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+fn algorithm_identifier<S: Pkcs8Suite>() -> Vec<u8> {
+    let content = [
+        der_encode(TAG_OID, EC_PUBLIC_KEY_OID),
+        der_encode(TAG_OID, S::CURVE_OID),
+    ]
+    .concat();
+    der_encode(TAG_SEQUENCE, &content)
+}
+
+// SEC1 uncompressed point encoding: `0x04 || x || y`.
+fn point_encode_uncompressed<S: Pkcs8Suite>(pt: &AffinePoint<S>) -> Vec<u8> {
+    let compressed = codec::point_encode::<S>(pt);
+    let mut y = Vec::new();
+    if let Some((_, y_coord)) = pt.xy() {
+        y_coord
+            .serialize_compressed(&mut y)
+            .expect("serialization to vec can't fail");
+    }
+    y.reverse();
+    [&[0x04], &compressed[1..], &y[..]].concat()
+}
+
+fn point_decode_uncompressed<S: Pkcs8Suite>(buf: &[u8]) -> Result<AffinePoint<S>, Error> {
+    let field_len = (buf.len().saturating_sub(1)) / 2;
+    if buf.len() != 1 + 2 * field_len || buf[0] != 0x04 || field_len == 0 {
+        return Err(Error::InvalidEncoding);
+    }
+    let parity = buf[buf.len() - 1] & 1;
+    let compressed = [&[0x02 | parity], &buf[1..1 + field_len]].concat();
+    let pt = codec::point_decode_strict::<S>(&compressed)?;
+    if point_encode_uncompressed::<S>(&pt) != buf {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(pt)
+}
+
+fn public_key_bit_string<S: Pkcs8Suite>(public: &Public<S>) -> Vec<u8> {
+    let content = [&[0x00], &point_encode_uncompressed::<S>(&public.0)[..]].concat();
+    der_encode(TAG_BIT_STRING, &content)
+}
+
+fn public_key_from_bit_string<S: Pkcs8Suite>(content: &[u8]) -> Result<Public<S>, Error> {
+    match content.split_first() {
+        Some((0x00, point)) => {
+            let public = Public(point_decode_uncompressed::<S>(point)?);
+            public.validate()?;
+            Ok(public)
+        }
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+impl<S: Pkcs8Suite> Secret<S> {
+    /// Encode as PKCS#8 DER.
+    pub fn to_pkcs8_der(&self) -> Vec<u8> {
+        let scalar = Zeroizing::new(codec::scalar_encode::<S>(&self.scalar));
+        let scalar = Zeroizing::new(der_encode(TAG_OCTET_STRING, &scalar));
+        let ec_private_key = Zeroizing::new(
+            [
+                &der_encode(TAG_INTEGER, &[0x01])[..],
+                &scalar[..],
+                &der_encode(TAG_CONTEXT_1, &public_key_bit_string(&self.public)),
+            ]
+            .concat(),
+        );
+        let ec_private_key = Zeroizing::new(der_encode(TAG_SEQUENCE, &ec_private_key));
+        let private_key = Zeroizing::new(der_encode(TAG_OCTET_STRING, &ec_private_key));
+        let content = Zeroizing::new(
+            [
+                &der_encode(TAG_INTEGER, &[0x00])[..],
+                &algorithm_identifier::<S>(),
+                &private_key[..],
+            ]
+            .concat(),
+        );
+        der_encode(TAG_SEQUENCE, &content)
+    }
+
+    /// Decode from PKCS#8 DER.
+    ///
+    /// Returns `Error::InvalidEncoding` if the encoding is not valid or if the
+    /// curve doesn't match the suite one, and `Error::InvalidData` if the
+    /// embedded public key (if any) doesn't match the secret.
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+        let content = der_decode_all(der, TAG_SEQUENCE)?;
+        let (version, content) = der_decode(content, TAG_INTEGER)?;
+        let (algorithm, content) = der_decode(content, TAG_SEQUENCE)?;
+        let private_key = der_decode_all(content, TAG_OCTET_STRING)?;
+        if version != [0x00] || algorithm != &algorithm_identifier::<S>()[2..] {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let content = der_decode_all(private_key, TAG_SEQUENCE)?;
+        let (version, content) = der_decode(content, TAG_INTEGER)?;
+        let (scalar, mut content) = der_decode(content, TAG_OCTET_STRING)?;
+        if version != [0x01] {
+            return Err(Error::InvalidEncoding);
+        }
+        // Optional parameters, must match the algorithm identifier ones.
+        if content.first() == Some(&TAG_CONTEXT_0) {
+            let (params, rest) = der_decode(content, TAG_CONTEXT_0)?;
+            if der_decode_all(params, TAG_OID)? != S::CURVE_OID {
+                return Err(Error::InvalidEncoding);
+            }
+            content = rest;
+        }
+        let secret = Self::from_scalar(codec::scalar_decode_strict::<S>(scalar)?);
+        // Optional public key, must match the secret one.
+        if !content.is_empty() {
+            let public_key = der_decode_all(content, TAG_CONTEXT_1)?;
+            let bit_string = der_decode_all(public_key, TAG_BIT_STRING)?;
+            if public_key_from_bit_string::<S>(bit_string)? != secret.public {
+                return Err(Error::InvalidData);
+            }
+        }
+        Ok(secret)
+    }
+
+    /// Encode as PKCS#8 PEM (`PRIVATE KEY` label).
+    pub fn to_pkcs8_pem(&self) -> String {
+        let der = Zeroizing::new(self.to_pkcs8_der());
+        pem_rfc7468::encode_string(PEM_PRIVATE_KEY, pem_rfc7468::LineEnding::LF, &der)
+            .expect("PEM encoding of DER can't fail")
+    }
+
+    /// Decode from PKCS#8 PEM (`PRIVATE KEY` label).
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Self, Error> {
+        let (label, der) =
+            pem_rfc7468::decode_vec(pem.as_bytes()).map_err(|_| Error::InvalidEncoding)?;
+        let der = Zeroizing::new(der);
+        match label {
+            PEM_PRIVATE_KEY => Self::from_pkcs8_der(&der),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+}
+
+impl<S: Pkcs8Suite> Public<S> {
+    /// Encode as `SubjectPublicKeyInfo` DER.
+    pub fn to_public_key_der(&self) -> Vec<u8> {
+        let content = [algorithm_identifier::<S>(), public_key_bit_string(self)].concat();
+        der_encode(TAG_SEQUENCE, &content)
+    }
+
+    /// Decode from `SubjectPublicKeyInfo` DER.
+    ///
+    /// Returns `Error::InvalidEncoding` if the encoding is not valid or if the curve
+    /// doesn't match the suite one, and `Error::InvalidPublicKey` if the public key
+    /// doesn't pass `Public::validate`.
+    pub fn from_public_key_der(der: &[u8]) -> Result<Self, Error> {
+        let content = der_decode_all(der, TAG_SEQUENCE)?;
+        let (algorithm, content) = der_decode(content, TAG_SEQUENCE)?;
+        if algorithm != &algorithm_identifier::<S>()[2..] {
+            return Err(Error::InvalidEncoding);
+        }
+        public_key_from_bit_string(der_decode_all(content, TAG_BIT_STRING)?)
+    }
+
+    /// Encode as `SubjectPublicKeyInfo` PEM (`PUBLIC KEY` label).
+    pub fn to_public_key_pem(&self) -> String {
+        let der = self.to_public_key_der();
+        pem_rfc7468::encode_string(PEM_PUBLIC_KEY, pem_rfc7468::LineEnding::LF, &der)
+            .expect("PEM encoding of DER can't fail")
+    }
+
+    /// Decode from `SubjectPublicKeyInfo` PEM (`PUBLIC KEY` label).
+    pub fn from_public_key_pem(pem: &str) -> Result<Self, Error> {
+        let (label, der) =
+            pem_rfc7468::decode_vec(pem.as_bytes()).map_err(|_| Error::InvalidEncoding)?;
+        match label {
+            PEM_PUBLIC_KEY => Self::from_public_key_der(&der),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::testing::TEST_SEED;
+
+    pub fn pkcs8_roundtrip<S: Pkcs8Suite>() {
+        let secret = Secret::<S>::from_seed(TEST_SEED);
+        let public = secret.public();
+
+        let der = secret.to_pkcs8_der();
+        assert_eq!(Secret::<S>::from_pkcs8_der(&der).unwrap(), secret);
+        assert!(Secret::<S>::from_pkcs8_der(&der[..der.len() - 1]).is_err());
+        assert!(Secret::<S>::from_pkcs8_der(&[&der[..], &[0]].concat()).is_err());
+        let pem = secret.to_pkcs8_pem();
+        assert_eq!(Secret::<S>::from_pkcs8_pem(&pem).unwrap(), secret);
+
+        let der = public.to_public_key_der();
+        assert_eq!(Public::<S>::from_public_key_der(&der).unwrap(), public);
+        assert!(Public::<S>::from_public_key_der(&der[..der.len() - 1]).is_err());
+        let pem = public.to_public_key_pem();
+        assert_eq!(Public::<S>::from_public_key_pem(&pem).unwrap(), public);
+
+        // PEM labels are checked.
+        assert!(Public::<S>::from_public_key_pem(&secret.to_pkcs8_pem()).is_err());
+        assert!(Secret::<S>::from_pkcs8_pem(&public.to_public_key_pem()).is_err());
+    }
+}
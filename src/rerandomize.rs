@@ -0,0 +1,126 @@
+//! Key re-randomization.
+//!
+//! A keypair is re-randomized with a tweak `t` as `x' = x + t` and `Y' = Y + t*G`.
+//! The re-randomized key is a regular keypair, thus it can be used with all the
+//! proof flavors (IETF, Pedersen and Ring), while the tweak keeps session keys
+//! unlinkable to the registered key.
+//!
+//! Optionally, the key owner can produce a linkage proof showing that `Y'` is a
+//! re-randomization of `Y`. This is a Schnorr proof of knowledge of `t` such that
+//! `Y' - Y = t*G`, with challenge `c = challenge([Y, Y', R], 0xD7 || ad)`.
+//!
+//! Here `x` and `Y` are the registered secret and public keys, `G` is the suite
+//! generator.
+
+use crate::*;
+
+const DOM_SEP: u8 = 0xD7;
+
+/// Proof that a public key is a re-randomization of another public key.
+#[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LinkageProof<S: Suite> {
+    r: AffinePoint<S>,
+    s: ScalarField<S>,
+}
+
+impl<S: Suite> Secret<S> {
+    /// Re-randomize the secret key with the given `tweak`.
+    ///
+    /// The public key of the returned secret is `self.public().rerandomize(tweak)`.
+    pub fn rerandomize(&self, tweak: ScalarField<S>) -> Self {
+        Self::from_scalar(self.scalar + tweak)
+    }
+
+    /// Re-randomize the secret key with the given `tweak`, producing a proof
+    /// linking the new public key to the current one.
+    ///
+    /// `ad` is additional data bound to the proof (e.g. a verifier challenge).
+    pub fn rerandomize_with_proof(
+        &self,
+        tweak: ScalarField<S>,
+        ad: impl AsRef<[u8]>,
+    ) -> (Self, LinkageProof<S>) {
+        let secret = self.rerandomize(tweak);
+        // k = nonce(t, Y')
+        let k = S::nonce(&tweak, Input(secret.public.0));
+        // R = k*G
+        let r = (S::generator() * k).into_affine();
+        let c = challenge(&self.public, &secret.public, &r, ad.as_ref());
+        // s = k + c*t
+        let s = k + c * tweak;
+        (secret, LinkageProof { r, s })
+    }
+}
+
+impl<S: Suite> Public<S> {
+    /// Re-randomize the public key with the given `tweak`.
+    pub fn rerandomize(&self, tweak: ScalarField<S>) -> Self {
+        Public((self.0 + S::generator() * tweak).into_affine())
+    }
+
+    /// Verify that `rerandomized` is a re-randomization of this public key.
+    ///
+    /// Returns `Error::VerificationFailure` if the proof is not valid.
+    pub fn verify_rerandomization(
+        &self,
+        rerandomized: &Public<S>,
+        ad: impl AsRef<[u8]>,
+        proof: &LinkageProof<S>,
+    ) -> Result<(), Error> {
+        let c = challenge(self, rerandomized, &proof.r, ad.as_ref());
+        // s*G = R + c*(Y' - Y)
+        if S::generator() * proof.s != proof.r + (rerandomized.0 - self.0) * c {
+            return Err(Error::VerificationFailure);
+        }
+        Ok(())
+    }
+}
+
+fn challenge<S: Suite>(
+    public: &Public<S>,
+    rerandomized: &Public<S>,
+    r: &AffinePoint<S>,
+    ad: &[u8],
+) -> ScalarField<S> {
+    let ad = [&[DOM_SEP][..], ad].concat();
+    S::challenge(&[&public.0, &rerandomized.0, r], &ad)
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod tests {
+    use crate::suites::testing::{Input, Public, ScalarField, Secret};
+    use crate::testing::{random_val, TEST_SEED};
+
+    #[test]
+    fn rerandomize_works() {
+        use crate::{ietf, pedersen, pedersen::Verifier as _};
+
+        let secret = Secret::from_seed(TEST_SEED);
+        let public = secret.public();
+        let tweak: ScalarField = random_val(None);
+
+        let (session, proof) = secret.rerandomize_with_proof(tweak, b"foo");
+        assert_eq!(session, secret.rerandomize(tweak));
+        assert_eq!(session.public(), public.rerandomize(tweak));
+        assert_ne!(session.public(), public);
+
+        assert!(public
+            .verify_rerandomization(&session.public(), b"foo", &proof)
+            .is_ok());
+        assert!(public
+            .verify_rerandomization(&session.public(), b"bar", &proof)
+            .is_err());
+        let other = secret.rerandomize(random_val(None)).public();
+        assert!(public
+            .verify_rerandomization(&other, b"foo", &proof)
+            .is_err());
+
+        // The re-randomized key is a regular key.
+        let input = Input::from(random_val(None));
+        let output = session.output(input);
+        let proof = ietf::Prover::prove(&session, input, output, b"foo");
+        assert!(ietf::Verifier::verify(&session.public(), input, output, b"foo", &proof).is_ok());
+        let (proof, _) = pedersen::Prover::prove(&session, input, output, b"foo");
+        assert!(Public::verify(input, output, b"foo", &proof).is_ok());
+    }
+}
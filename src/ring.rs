@@ -3,6 +3,8 @@ use crate::*;
 use ark_ec::short_weierstrass::SWCurveConfig;
 use pedersen::{PedersenSuite, Proof as PedersenProof};
 
+pub mod tickets;
+
 /// Ring suite.
 pub trait RingSuite: PedersenSuite {
     /// Pairing type.
@@ -15,16 +17,20 @@ pub trait RingSuite: PedersenSuite {
     const ACCUMULATOR_BASE: AffinePoint<Self>;
 }
 
-/// Polinomial Commitment Scheme (KZG)
-type Pcs<S> = ring_proof::pcs::kzg::KZG<<S as RingSuite>::Pairing>;
+pub use ring_proof::pcs::PCS;
+
+/// KZG Polinomial Commitment Scheme.
+///
+/// Default PCS used by the ring types.
+pub type Kzg<S> = ring_proof::pcs::kzg::KZG<<S as RingSuite>::Pairing>;
 
 /// Single PCS commitment.
-type PcsCommitment<S> = ring_proof::pcs::kzg::commitment::KzgCommitment<<S as RingSuite>::Pairing>;
+type PcsCommitment<S, P> = <P as PCS<BaseField<S>>>::C;
 
-/// KZG "Polynomial Commitment Scheme" (PCS) parameters.
+/// "Polynomial Commitment Scheme" (PCS) parameters.
 ///
-/// Basically powers of tau SRS.
-pub type PcsParams<S> = ring_proof::pcs::kzg::urs::URS<<S as RingSuite>::Pairing>;
+/// For KZG, basically powers of tau SRS.
+pub type PcsParams<S, P = Kzg<S>> = <P as PCS<BaseField<S>>>::Params;
 
 /// Polynomial "Interactive Oracle Proof" (IOP) parameters.
 ///
@@ -33,39 +39,255 @@ pub type PcsParams<S> = ring_proof::pcs::kzg::urs::URS<<S as RingSuite>::Pairing
 type PiopParams<S> = ring_proof::PiopParams<BaseField<S>, CurveConfig<S>>;
 
 /// Ring keys commitment.
-pub type RingCommitment<S> = ring_proof::FixedColumnsCommitted<BaseField<S>, PcsCommitment<S>>;
+pub type RingCommitment<S, P = Kzg<S>> =
+    ring_proof::FixedColumnsCommitted<BaseField<S>, PcsCommitment<S, P>>;
 
 /// Ring prover key.
-pub type ProverKey<S> =
-    ring_proof::ProverKey<BaseField<S>, Pcs<S>, ark_ec::short_weierstrass::Affine<CurveConfig<S>>>;
+pub type ProverKey<S, P = Kzg<S>> =
+    ring_proof::ProverKey<BaseField<S>, P, ark_ec::short_weierstrass::Affine<CurveConfig<S>>>;
 
 /// Ring verifier key.
-pub type VerifierKey<S> = ring_proof::VerifierKey<BaseField<S>, Pcs<S>>;
+pub type VerifierKey<S, P = Kzg<S>> = ring_proof::VerifierKey<BaseField<S>, P>;
 
 /// Ring prover.
-pub type RingProver<S> = ring_proof::ring_prover::RingProver<BaseField<S>, Pcs<S>, CurveConfig<S>>;
+///
+/// `Send + Sync`, thus it can be shared between threads (e.g. via `Arc`).
+pub type RingProver<S, P = Kzg<S>> =
+    ring_proof::ring_prover::RingProver<BaseField<S>, P, CurveConfig<S>>;
 
 /// Ring verifier.
-pub type RingVerifier<S> =
-    ring_proof::ring_verifier::RingVerifier<BaseField<S>, Pcs<S>, CurveConfig<S>>;
+///
+/// `Send + Sync`, thus it can be shared between threads (e.g. via `Arc`).
+pub type RingVerifier<S, P = Kzg<S>> =
+    ring_proof::ring_verifier::RingVerifier<BaseField<S>, P, CurveConfig<S>>;
 
 /// Actual ring proof.
-pub type RingProof<S> = ring_proof::RingProof<BaseField<S>, Pcs<S>>;
+pub type RingProof<S, P = Kzg<S>> = ring_proof::RingProof<BaseField<S>, P>;
+
+/// Ring member public key together with its weight.
+///
+/// The weight is the number of ring slots the member occupies.
+pub type WeightedKey<S> = (AffinePoint<S>, usize);
 
 /// Ring proof bundled together with a Pedersen proof.
 ///
 /// Pedersen proof is used to provide VRF capability.
 #[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
-pub struct Proof<S: RingSuite>
+pub struct Proof<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig,
 {
     pub pedersen_proof: PedersenProof<S>,
-    pub ring_proof: RingProof<S>,
+    pub ring_proof: RingProof<S, P>,
+}
+
+impl<S: RingSuite, P: PCS<BaseField<S>>> Proof<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Get the Pedersen public key commitment carried by the proof.
+    ///
+    /// This is the blinded public key proven to be a member of the ring.
+    pub fn key_commitment(&self) -> AffinePoint<S> {
+        self.pedersen_proof.key_commitment()
+    }
+}
+
+impl<S: RingSuite, P: PCS<BaseField<S>>> TryFrom<&[u8]> for Proof<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    type Error = Error;
+
+    /// Decode a proof serialized using the arkworks compressed format.
+    ///
+    /// Returns `Error::InvalidEncoding` if `buf` is not a valid encoding or if
+    /// it contains trailing bytes.
+    fn try_from(mut buf: &[u8]) -> Result<Self, Self::Error> {
+        let proof = Self::deserialize_compressed(&mut buf)?;
+        match buf.is_empty() {
+            true => Ok(proof),
+            false => Err(Error::InvalidEncoding),
+        }
+    }
+}
+
+impl<S: RingSuite, P: PCS<BaseField<S>>> From<Proof<S, P>> for Vec<u8>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Serialize the proof using the arkworks compressed format.
+    fn from(proof: Proof<S, P>) -> Self {
+        let mut buf = Vec::with_capacity(proof.compressed_size());
+        proof
+            .serialize_compressed(&mut buf)
+            .expect("serialization to vec can't fail");
+        buf
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: RingSuite, P: PCS<BaseField<S>>> serde::Serialize for Proof<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Serialized using the arkworks compressed format.
+    fn serialize<T: serde::Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+        let mut buf = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut buf)
+            .map_err(|_| serde::ser::Error::custom("serialization failure"))?;
+        utils::serde_serialize(&buf, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: RingSuite, P: PCS<BaseField<S>>> serde::Deserialize<'de> for Proof<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let buf = utils::serde_deserialize(deserializer)?;
+        Self::try_from(&buf[..]).map_err(|_| serde::de::Error::custom("invalid encoding"))
+    }
+}
+
+/// Linkability tag.
+///
+/// Deterministic function of the signer secret key and of the VRF input, obtained
+/// by hashing the VRF output (`x * I`). Two proofs produced by the same ring member
+/// for the same input have the same tag, regardless of the ring and additional data.
+/// The tag doesn't reveal the signer identity.
+///
+/// As the output is bound to the ring member secret key by the proof, verifiers can
+/// trust a tag once the proof has been verified.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LinkabilityTag(pub [u8; 32]);
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for LinkabilityTag {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+    }
+}
+
+impl core::fmt::Display for LinkabilityTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        utils::hex_fmt(f, &self.0)
+    }
+}
+
+impl core::fmt::Debug for LinkabilityTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LinkabilityTag({})", self)
+    }
+}
+
+impl core::str::FromStr for LinkabilityTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let buf = utils::hex_decode(s)?;
+        buf.try_into().map(Self).map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LinkabilityTag {
+    fn serialize<T: serde::Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+        utils::serde_serialize(&self.0, serializer)
+    }
 }
 
-pub trait Prover<S: RingSuite>
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LinkabilityTag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let buf = utils::serde_deserialize(deserializer)?;
+        buf.try_into()
+            .map(Self)
+            .map_err(|_| serde::de::Error::custom("invalid linkability tag length"))
+    }
+}
+
+impl LinkabilityTag {
+    /// Construct the tag from the VRF output.
+    pub fn new<S: Suite>(output: &Output<S>) -> Self {
+        const DOM_SEP: u8 = 0x4C;
+        let mut buf = [S::SUITE_ID, &[DOM_SEP]].concat();
+        S::Codec::point_encode(&output.0, &mut buf);
+        let hash = utils::hash::<S::Hasher>(&buf);
+        Self(utils::hash_prefix_32::<S::Hasher>(&hash))
+    }
+}
+
+/// Ring proof generation phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingPhase {
+    /// Pedersen VRF proof generation.
+    Pedersen,
+    /// Ring membership proof generation.
+    Ring,
+}
+
+/// Sink for proof generation progress notifications.
+///
+/// The backend doesn't report progress within a phase, thus notifications are
+/// only emitted at the phase boundaries.
+pub trait ProgressSink {
+    /// Called when `phase` reaches `percent` (0..=100) completion.
+    fn progress(&self, phase: ProvingPhase, percent: u8);
+}
+
+/// No-op progress sink.
+impl ProgressSink for () {
+    fn progress(&self, _phase: ProvingPhase, _percent: u8) {}
+}
+
+impl<F: Fn(ProvingPhase, u8)> ProgressSink for F {
+    fn progress(&self, phase: ProvingPhase, percent: u8) {
+        self(phase, percent)
+    }
+}
+
+/// Cancellation token for proof generation.
+///
+/// Cloned tokens share the same state, thus a token can be cancelled from
+/// another thread while the proof generation is in progress.
+///
+/// Cancellation is honored before the start of each proving phase.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(ark_std::sync::Arc<core::sync::atomic::AtomicBool>);
+
+impl CancellationToken {
+    /// Construct a new, not cancelled, token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation.
+    pub fn cancel(&self) {
+        self.0.store(true, core::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Check if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        match self.is_cancelled() {
+            true => Err(Error::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+pub trait Prover<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig,
@@ -76,11 +298,70 @@ where
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-        prover: &RingProver<S>,
-    ) -> Proof<S>;
+        prover: &RingProver<S, P>,
+    ) -> Proof<S, P>;
+
+    /// Generate a proof checking the Pedersen proof before returning it.
+    ///
+    /// Fault attack countermeasure, see `pedersen::Prover::prove_checked`. The ring
+    /// membership proof, which doesn't involve the secret key, is not verified as
+    /// this would require the ring verifier key.
+    ///
+    /// Returns `Error::VerificationFailure` if the check fails, in which case
+    /// nothing should be disclosed.
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S, P>,
+    ) -> Result<Proof<S, P>, Error>;
+
+    /// Generate a proof reporting progress to `sink` and honoring `cancel`.
+    ///
+    /// Progress is reported at the phase boundaries, i.e. 0 when a phase starts and
+    /// 100 when it completes.
+    ///
+    /// Returns `Error::Cancelled` if cancellation has been requested before the start
+    /// of one of the phases. A request arriving during the last phase is not honored,
+    /// as the proof is already complete when the phase returns.
+    fn prove_with_progress(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S, P>,
+        sink: &impl ProgressSink,
+        cancel: &CancellationToken,
+    ) -> Result<Proof<S, P>, Error>;
+
+    /// Generate a proof sampling the Pedersen blinding factor from `rng`.
+    ///
+    /// Allows to route the proof randomness through a caller provided (e.g. audited)
+    /// cryptographically secure RNG instead of the default deterministic derivation.
+    fn prove_with_rng(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S, P>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> Proof<S, P>;
+
+    /// Generate a proof together with the signer linkability tag.
+    fn prove_linkable(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        prover: &RingProver<S, P>,
+    ) -> (Proof<S, P>, LinkabilityTag) {
+        let proof = self.prove(input, output, ad, prover);
+        (proof, LinkabilityTag::new(&output))
+    }
 }
 
-impl<S: RingSuite> Prover<S> for Secret<S>
+impl<S: RingSuite, P: PCS<BaseField<S>>, T: VrfSigner<S> + ?Sized> Prover<S, P> for T
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig,
@@ -90,12 +371,72 @@ where
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-        ring_prover: &RingProver<S>,
-    ) -> Proof<S> {
+        ring_prover: &RingProver<S, P>,
+    ) -> Proof<S, P> {
+        use pedersen::Prover as PedersenProver;
+        let (pedersen_proof, secret_blinding) =
+            <Self as PedersenProver<S>>::prove(self, input, output, ad);
+        let ring_proof = ring_prover.prove(*secret_blinding.expose());
+        Proof {
+            pedersen_proof,
+            ring_proof,
+        }
+    }
+
+    fn prove_checked(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        ring_prover: &RingProver<S, P>,
+    ) -> Result<Proof<S, P>, Error> {
+        use pedersen::Prover as PedersenProver;
+        let (pedersen_proof, secret_blinding) =
+            <Self as PedersenProver<S>>::prove_checked(self, input, output, ad)?;
+        let ring_proof = ring_prover.prove(*secret_blinding.expose());
+        Ok(Proof {
+            pedersen_proof,
+            ring_proof,
+        })
+    }
+
+    fn prove_with_progress(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        ring_prover: &RingProver<S, P>,
+        sink: &impl ProgressSink,
+        cancel: &CancellationToken,
+    ) -> Result<Proof<S, P>, Error> {
         use pedersen::Prover as PedersenProver;
+        cancel.check()?;
+        sink.progress(ProvingPhase::Pedersen, 0);
         let (pedersen_proof, secret_blinding) =
             <Self as PedersenProver<S>>::prove(self, input, output, ad);
-        let ring_proof = ring_prover.prove(secret_blinding);
+        sink.progress(ProvingPhase::Pedersen, 100);
+        cancel.check()?;
+        sink.progress(ProvingPhase::Ring, 0);
+        let ring_proof = ring_prover.prove(*secret_blinding.expose());
+        sink.progress(ProvingPhase::Ring, 100);
+        Ok(Proof {
+            pedersen_proof,
+            ring_proof,
+        })
+    }
+
+    fn prove_with_rng(
+        &self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        ring_prover: &RingProver<S, P>,
+        rng: &mut (impl ark_std::rand::RngCore + ark_std::rand::CryptoRng),
+    ) -> Proof<S, P> {
+        use pedersen::Prover as PedersenProver;
+        let (pedersen_proof, secret_blinding) =
+            <Self as PedersenProver<S>>::prove_with_rng(self, input, output, ad, rng);
+        let ring_proof = ring_prover.prove(*secret_blinding.expose());
         Proof {
             pedersen_proof,
             ring_proof,
@@ -103,22 +444,42 @@ where
     }
 }
 
-pub trait Verifier<S: RingSuite>
+pub trait Verifier<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig,
 {
     /// Verify a proof for the given input/output and user additional data.
+    ///
+    /// Returns `Error::VerificationFailure` if the Pedersen proof is not valid
+    /// and `Error::RingProofFailure` if the ring membership proof is not valid.
     fn verify(
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-        sig: &Proof<S>,
-        verifier: &RingVerifier<S>,
+        sig: &Proof<S, P>,
+        verifier: &RingVerifier<S, P>,
     ) -> Result<(), Error>;
+
+    /// Verify a proof together with the signer linkability tag.
+    ///
+    /// Returns `Error::LinkabilityTagMismatch` if `tag` is not the one of `output`.
+    fn verify_linkable(
+        input: Input<S>,
+        output: Output<S>,
+        ad: impl AsRef<[u8]>,
+        sig: &Proof<S, P>,
+        verifier: &RingVerifier<S, P>,
+        tag: &LinkabilityTag,
+    ) -> Result<(), Error> {
+        if !utils::ct_eq(&LinkabilityTag::new(&output).0, &tag.0) {
+            return Err(Error::LinkabilityTagMismatch);
+        }
+        Self::verify(input, output, ad, sig, verifier)
+    }
 }
 
-impl<S: RingSuite> Verifier<S> for Public<S>
+impl<S: RingSuite, P: PCS<BaseField<S>> + Clone> Verifier<S, P> for Public<S>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig,
@@ -128,37 +489,251 @@ where
         input: Input<S>,
         output: Output<S>,
         ad: impl AsRef<[u8]>,
-        sig: &Proof<S>,
-        verifier: &RingVerifier<S>,
+        sig: &Proof<S, P>,
+        verifier: &RingVerifier<S, P>,
     ) -> Result<(), Error> {
         use pedersen::Verifier as PedersenVerifier;
         <Self as PedersenVerifier<S>>::verify(input, output, ad, &sig.pedersen_proof)?;
-        let key_commitment = sig.pedersen_proof.key_commitment().into_sw();
+        let key_commitment = sig
+            .key_commitment()
+            .try_into_sw()
+            .ok_or(Error::RingProofFailure)?;
         if !verifier.verify_ring_proof(sig.ring_proof.clone(), key_commitment) {
-            return Err(Error::VerificationFailure);
+            return Err(Error::RingProofFailure);
         }
         Ok(())
     }
 }
 
-#[derive(Clone)]
-pub struct RingContext<S: RingSuite>
+/// Ring context.
+///
+/// Generic over the Polynomial Commitment Scheme used by the ring proof backend,
+/// which defaults to KZG.
+///
+/// Construction from a KZG SRS, ring roots, sizes and serialization are only
+/// available for the KZG instance. Contexts for other schemes are constructed
+/// via `RingContext::from_pcs_params`.
+pub struct RingContext<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig + Clone,
 {
-    pcs_params: PcsParams<S>,
+    pcs_params: PcsParams<S, P>,
     piop_params: PiopParams<S>,
 }
 
+impl<S: RingSuite, P: PCS<BaseField<S>>> Clone for RingContext<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    PcsParams<S, P>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pcs_params: self.pcs_params.clone(),
+            piop_params: self.piop_params.clone(),
+        }
+    }
+}
+
 // Evaluation domain size required for the given ring size.
 #[inline(always)]
 fn domain_size<S: RingSuite>(ring_size: usize) -> usize {
     1 << ark_std::log2(ring_size + ScalarField::<S>::MODULUS_BIT_SIZE as usize + 4)
 }
 
+// Max ring size supported by the KZG SRS.
+//
+// That is, the max ring size whose evaluation domain is covered by the powers of tau.
+fn srs_max_ring_size<S: RingSuite>(pcs_params: &PcsParams<S>) -> usize
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+{
+    let max_domain_size = pcs_params.powers_in_g1.len().saturating_sub(1) / 3;
+    if max_domain_size == 0 {
+        return 0;
+    }
+    // Domain sizes are powers of two.
+    let max_domain_size = 1 << (usize::BITS - 1 - max_domain_size.leading_zeros());
+    max_domain_size.saturating_sub(ScalarField::<S>::MODULUS_BIT_SIZE as usize + 4)
+}
+
+/// Total weight of a weighted ring.
+///
+/// Returns `None` on overflow.
+pub fn total_weight<S: RingSuite>(members: &[WeightedKey<S>]) -> Option<usize> {
+    members
+        .iter()
+        .try_fold(0_usize, |acc, (_, weight)| acc.checked_add(*weight))
+}
+
+/// Slot index of the `member`-th entry of a weighted ring.
+///
+/// This is the index to be used to construct the `RingProver` for the member
+/// once the ring has been expanded via `RingContext::weighted_ring`.
+///
+/// Returns `None` if `member` is out of bounds or if its weight is zero.
+pub fn weighted_key_index<S: RingSuite>(
+    members: &[WeightedKey<S>],
+    member: usize,
+) -> Option<usize> {
+    if members.get(member)?.1 == 0 {
+        return None;
+    }
+    total_weight::<S>(&members[..member])
+}
+
+/// Index of `public` within the ring `pks`.
+///
+/// The whole ring is scanned and every key is encoded and compared, without
+/// short-circuiting and selecting the index with a branchless mask. Thus the running
+/// time and the memory access pattern don't depend on the position of `public`.
+///
+/// If `public` is present more than once, the last index is returned.
+///
+/// Only the lookup is constant time. Proof generation is not, see `RingContext::prover`.
+pub fn key_index<S: RingSuite>(pks: &[AffinePoint<S>], public: &AffinePoint<S>) -> Option<usize> {
+    let encode = |pk: &AffinePoint<S>| {
+        let mut buf = Vec::new();
+        pk.serialize_compressed(&mut buf)
+            .expect("serialization to vec can't fail");
+        buf
+    };
+    let target = encode(public);
+    let (index, found) = pks
+        .iter()
+        .enumerate()
+        .fold((0, 0), |(index, found), (i, pk)| {
+            let mask = 0_usize.wrapping_sub(utils::ct_eq(&encode(pk), &target) as usize);
+            ((index & !mask) | (i & mask), found | mask)
+        });
+    (found != 0).then_some(index)
+}
+
+// KZG specific functionality.
 #[allow(private_bounds)]
-impl<S: RingSuite> RingContext<S>
+impl<S: RingSuite> RingContext<S, Kzg<S>>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    /// Construct a new ring context from KZG SRS suitable for the given ring size.
+    ///
+    /// Only the powers of tau required by the ring size are retained.
+    ///
+    /// This is KZG specific. For other commitment schemes use
+    /// `RingContext::from_pcs_params`, which leaves the parameters check to the caller.
+    ///
+    /// See also `RingContextBuilder`.
+    ///
+    /// Returns `Error::SrsTooSmall` if the SRS doesn't contain enough powers of tau
+    /// for the domain required by the ring size.
+    pub fn from_srs(ring_size: usize, mut pcs_params: PcsParams<S>) -> Result<Self, Error> {
+        let domain_size = domain_size::<S>(ring_size);
+        if pcs_params.powers_in_g1.len() < 3 * domain_size + 1 {
+            return Err(Error::SrsTooSmall {
+                expected: 3 * domain_size + 1,
+                actual: pcs_params.powers_in_g1.len(),
+            });
+        }
+        if pcs_params.powers_in_g2.len() < 2 {
+            return Err(Error::InvalidData);
+        }
+        // Keep only the required powers of tau.
+        pcs_params.powers_in_g1.truncate(3 * domain_size + 1);
+        pcs_params.powers_in_g2.truncate(2);
+        Ok(Self::from_pcs_params(ring_size, pcs_params))
+    }
+
+    /// Construct `VerifierKey` instance for the ring previously committed.
+    ///
+    /// The `RingCommitment` instance can be obtained via the `VerifierKey::commitment()` method.
+    ///
+    /// This allows to quickly reconstruct the verifier key without having to recompute the
+    /// keys commitment.
+    pub fn verifier_key_from_commitment(&self, commitment: RingCommitment<S>) -> VerifierKey<S> {
+        use ring_proof::pcs::PcsParams;
+        VerifierKey::<S>::from_commitment_and_kzg_vk(commitment, self.pcs_params.raw_vk())
+    }
+
+    /// Export the ring commitment as constant size "ring root" bytes.
+    ///
+    /// The ring root is the compressed serialization of the `RingCommitment` and can
+    /// be stored in place of the full verifier key (e.g. in chain storage).
+    ///
+    /// Returns `Error::InvalidData` if `N` doesn't match the serialized commitment size.
+    pub fn ring_root<const N: usize>(
+        &self,
+        verifier_key: &VerifierKey<S>,
+    ) -> Result<[u8; N], Error> {
+        let commitment = verifier_key.commitment();
+        if commitment.compressed_size() != N {
+            return Err(Error::InvalidData);
+        }
+        let mut root = [0; N];
+        commitment.serialize_compressed(&mut root[..])?;
+        Ok(root)
+    }
+
+    /// Construct `VerifierKey` instance from a ring root previously exported
+    /// with `ring_root`.
+    pub fn verifier_key_from_ring_root<const N: usize>(
+        &self,
+        root: &[u8; N],
+    ) -> Result<VerifierKey<S>, Error> {
+        let commitment = RingCommitment::<S>::deserialize_compressed(&root[..])?;
+        Ok(self.verifier_key_from_commitment(commitment))
+    }
+
+    /// Compressed serialized sizes of the objects managed by this context.
+    ///
+    /// Sizes don't depend on the ring keys, thus they are measured on sample objects
+    /// constructed for a ring containing a single key. This is as expensive as the
+    /// generation of a ring proof, thus the result is better computed once and reused.
+    /// For the size of a `ProverKey`, which depends on the domain size, use
+    /// `compressed_size()` on the key instance.
+    pub fn sizes(&self) -> RingSizes {
+        let secret = Secret::<S>::from_seed(b"ring-sizes");
+        let pks = [secret.public().0];
+        let verifier_key = self.verifier_key(&pks);
+        let prover = self.prover_from_owned_key(self.prover_key(&pks), 0);
+        let input = Input::<S>(S::generator());
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, b"", &prover);
+        RingSizes {
+            pcs_params: self.pcs_params.compressed_size(),
+            ring_commitment: verifier_key.commitment().compressed_size(),
+            verifier_key: verifier_key.compressed_size(),
+            pedersen_proof: proof.pedersen_proof.compressed_size(),
+            ring_proof: proof.ring_proof.compressed_size(),
+            proof: proof.compressed_size(),
+        }
+    }
+}
+
+/// Compressed serialized sizes of the ring objects.
+///
+/// See `RingContext::sizes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RingSizes {
+    /// PCS parameters (i.e. the `RingContext` itself).
+    pub pcs_params: usize,
+    /// Ring commitment (also the ring root size).
+    pub ring_commitment: usize,
+    /// Verifier key.
+    pub verifier_key: usize,
+    /// Pedersen VRF proof.
+    pub pedersen_proof: usize,
+    /// Ring membership proof.
+    pub ring_proof: usize,
+    /// Full ring VRF proof.
+    pub proof: usize,
+}
+
+#[allow(private_bounds)]
+impl<S: RingSuite, P: PCS<BaseField<S>>> RingContext<S, P>
 where
     BaseField<S>: ark_ff::PrimeField,
     CurveConfig<S>: SWCurveConfig + Clone,
@@ -173,32 +748,26 @@ where
 
     /// Construct a new random ring context suitable for the given ring size.
     pub fn from_rand(ring_size: usize, rng: &mut impl ark_std::rand::RngCore) -> Self {
-        use ring_proof::pcs::PCS;
         let domain_size = domain_size::<S>(ring_size);
-        let pcs_params = Pcs::<S>::setup(3 * domain_size, rng);
-        Self::from_srs(ring_size, pcs_params).expect("PCS params is correct")
+        let pcs_params = P::setup(3 * domain_size, rng);
+        Self::from_pcs_params(ring_size, pcs_params)
     }
 
-    pub fn from_srs(ring_size: usize, mut pcs_params: PcsParams<S>) -> Result<Self, Error> {
-        let domain_size = domain_size::<S>(ring_size);
-        if pcs_params.powers_in_g1.len() < 3 * domain_size + 1 || pcs_params.powers_in_g2.len() < 2
-        {
-            return Err(Error::InvalidData);
-        }
-        // Keep only the required powers of tau.
-        pcs_params.powers_in_g1.truncate(3 * domain_size + 1);
-        pcs_params.powers_in_g2.truncate(2);
-
+    /// Construct a new ring context from PCS parameters suitable for the given ring size.
+    ///
+    /// The PCS parameters are required to support polynomials with degree up to three
+    /// times the evaluation domain size. This is not checked here, as the check is PCS
+    /// specific. For KZG prefer `RingContext::from_srs`, which performs the check.
+    pub fn from_pcs_params(ring_size: usize, pcs_params: PcsParams<S, P>) -> Self {
         let piop_params = PiopParams::<S>::setup(
-            ring_proof::Domain::new(domain_size, true),
+            ring_proof::Domain::new(domain_size::<S>(ring_size), true),
             S::BLINDING_BASE.into_sw(),
             S::ACCUMULATOR_BASE.into_sw(),
         );
-
-        Ok(Self {
+        Self {
             pcs_params,
             piop_params,
-        })
+        }
     }
 
     /// The max ring size this context is able to manage.
@@ -207,10 +776,104 @@ where
         self.piop_params.keyset_part_size
     }
 
+    /// Expand a weighted ring into the sequence of ring slots.
+    ///
+    /// Each member occupies a number of consecutive slots equal to its weight.
+    /// Members with zero weight are skipped.
+    ///
+    /// Proofs only reveal that the signer owns one of the ring slots, thus
+    /// every slot is an equally likely signer from the verifier point of view
+    /// and a member is attributed a `weight / total_weight` share of the ring.
+    /// Note that VRF outputs are bound to the secret key and are not affected
+    /// by the weight: the number of slots doesn't give more outputs to a member.
+    ///
+    /// Returns `Error::RingTooLarge` if the total weight exceeds `self.max_ring_size()`.
+    pub fn weighted_ring(&self, members: &[WeightedKey<S>]) -> Result<Vec<AffinePoint<S>>, Error> {
+        let total = total_weight::<S>(members).ok_or(Error::InvalidData)?;
+        self.check_ring_size(total)?;
+        let mut slots = Vec::with_capacity(total);
+        members
+            .iter()
+            .for_each(|(pk, weight)| slots.extend(core::iter::repeat(*pk).take(*weight)));
+        Ok(slots)
+    }
+
+    /// Check that a ring of `ring_size` keys can be managed by this context.
+    ///
+    /// Returns `Error::RingTooLarge` if `ring_size > self.max_ring_size()`.
+    pub fn check_ring_size(&self, ring_size: usize) -> Result<(), Error> {
+        if ring_size > self.max_ring_size() {
+            return Err(Error::RingTooLarge {
+                expected: self.max_ring_size(),
+                actual: ring_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the `pks` ring can be managed by this context.
+    ///
+    /// Returns `Error::RingTooLarge` if `pks.len() > self.max_ring_size()` and
+    /// `Error::InvalidPublicKey` if some key can't be mapped to the SW form used by
+    /// the ring proof backend (e.g. the identity point).
+    pub fn check_ring(&self, pks: &[AffinePoint<S>]) -> Result<(), Error> {
+        self.check_ring_size(pks.len())?;
+        if pks.iter().any(|pk| pk.try_into_sw().is_none()) {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(())
+    }
+
+    /// Construct a `ProverKey` instance for the given ring, checking its keys.
+    ///
+    /// Returns the errors of `check_ring`.
+    pub fn try_prover_key(&self, pks: &[AffinePoint<S>]) -> Result<ProverKey<S, P>, Error> {
+        self.check_ring(pks)?;
+        Ok(self.prover_key(pks))
+    }
+
+    /// Construct a `VerifierKey` instance for the given ring, checking its keys.
+    ///
+    /// Returns the errors of `check_ring`.
+    pub fn try_verifier_key(&self, pks: &[AffinePoint<S>]) -> Result<VerifierKey<S, P>, Error> {
+        self.check_ring(pks)?;
+        Ok(self.verifier_key(pks))
+    }
+
+    /// Construct `RingProver` checking the key index bounds.
+    ///
+    /// `ring_size` is the number of keys used to construct the `ProverKey`.
+    ///
+    /// Returns `Error::RingTooLarge` if `ring_size > self.max_ring_size()` and
+    /// `Error::KeyIndexOutOfBounds` if `key_index >= ring_size`.
+    pub fn try_prover(
+        &self,
+        prover_key: &ProverKey<S, P>,
+        ring_size: usize,
+        key_index: usize,
+    ) -> Result<RingProver<S, P>, Error>
+    where
+        ProverKey<S, P>: Clone,
+    {
+        self.check_ring_size(ring_size)?;
+        if key_index >= ring_size {
+            return Err(Error::KeyIndexOutOfBounds {
+                expected: ring_size,
+                actual: key_index,
+            });
+        }
+        Ok(self.prover(prover_key, key_index))
+    }
+
     /// Construct a `ProverKey` instance for the given ring.
     ///
     /// Note: if `pks.len() > self.max_ring_size()` the extra keys in the tail are ignored.
-    pub fn prover_key(&self, pks: &[AffinePoint<S>]) -> ProverKey<S> {
+    /// Use `try_prover_key` to get an error instead.
+    ///
+    /// # Panics
+    ///
+    /// If some key can't be mapped to SW form (see `check_ring`).
+    pub fn prover_key(&self, pks: &[AffinePoint<S>]) -> ProverKey<S, P> {
         let pks = SWMapping::to_sw_slice(&pks[..pks.len().min(self.max_ring_size())]);
         ring_proof::index(&self.pcs_params, &self.piop_params, &pks).0
     }
@@ -219,8 +882,33 @@ where
     ///
     /// Key index is the prover index within the `pks` sequence passed to construct the
     /// `ProverKey` via the `prover_key` method.
-    pub fn prover(&self, prover_key: ProverKey<S>, key_index: usize) -> RingProver<S> {
-        RingProver::<S>::init(
+    ///
+    /// Proof generation is not constant time with respect to `key_index`: the prover
+    /// column selection is performed by the ring-proof backend, which branches on it.
+    /// Thus the signer position may leak through timing to an observer of the prover.
+    ///
+    /// The key is borrowed, thus one key (possibly shared via `Arc`) can back many
+    /// provers (e.g. for different indices). Note that the backend requires an owned
+    /// key, thus each prover holds its own copy of it.
+    ///
+    /// # Panics
+    ///
+    /// Proving panics if `key_index` is out of the ring bounds.
+    /// Use `try_prover` to get an error instead.
+    pub fn prover(&self, prover_key: &ProverKey<S, P>, key_index: usize) -> RingProver<S, P>
+    where
+        ProverKey<S, P>: Clone,
+    {
+        self.prover_from_owned_key(prover_key.clone(), key_index)
+    }
+
+    // Construct `RingProver` consuming the `ProverKey`.
+    fn prover_from_owned_key(
+        &self,
+        prover_key: ProverKey<S, P>,
+        key_index: usize,
+    ) -> RingProver<S, P> {
+        RingProver::<S, P>::init(
             prover_key,
             self.piop_params.clone(),
             key_index,
@@ -231,25 +919,24 @@ where
     /// Construct a `VerifierKey` instance for the given ring.
     ///
     /// Note: if `pks.len() > self.max_ring_size()` the extra keys in the tail are ignored.
-    pub fn verifier_key(&self, pks: &[AffinePoint<S>]) -> VerifierKey<S> {
-        let pks = SWMapping::to_sw_slice(&pks[..pks.len().min(self.max_ring_size())]);
-        ring_proof::index(&self.pcs_params, &self.piop_params, &pks).1
-    }
-
-    /// Construct `VerifierKey` instance for the ring previously committed.
+    /// Use `try_verifier_key` to get an error instead.
     ///
-    /// The `RingCommitment` instance can be obtained via the `VerifierKey::commitment()` method.
+    /// # Panics
     ///
-    /// This allows to quickly reconstruct the verifier key without having to recompute the
-    /// keys commitment.
-    pub fn verifier_key_from_commitment(&self, commitment: RingCommitment<S>) -> VerifierKey<S> {
-        use ring_proof::pcs::PcsParams;
-        VerifierKey::<S>::from_commitment_and_kzg_vk(commitment, self.pcs_params.raw_vk())
+    /// If some key can't be mapped to SW form (see `check_ring`).
+    pub fn verifier_key(&self, pks: &[AffinePoint<S>]) -> VerifierKey<S, P> {
+        let pks = SWMapping::to_sw_slice(&pks[..pks.len().min(self.max_ring_size())]);
+        ring_proof::index(&self.pcs_params, &self.piop_params, &pks).1
     }
 
     /// Construct `RingVerifier` from `VerifierKey`.
-    pub fn verifier(&self, verifier_key: VerifierKey<S>) -> RingVerifier<S> {
-        RingVerifier::<S>::init(
+    ///
+    /// The Fiat-Shamir transcript is bound to the ring: both `RingProver` and
+    /// `RingVerifier` initialization absorb the verifier key (i.e. the ring commitment
+    /// together with the PCS verification key) into the transcript. Thus a proof
+    /// doesn't verify against a different ring constructed with the same parameters.
+    pub fn verifier(&self, verifier_key: VerifierKey<S, P>) -> RingVerifier<S, P> {
+        RingVerifier::<S, P>::init(
             verifier_key,
             self.piop_params.clone(),
             ring_proof::Transcript::new(b""),
@@ -293,8 +980,14 @@ where
             compress,
             validate,
         )?;
-        let domain_size = (pcs_params.powers_in_g1.len() - 1) / 3;
-        Self::from_srs(domain_size, pcs_params)
+        // Max ring size supported by the domain which was used to trim the powers.
+        let ring_size = Some(srs_max_ring_size::<S>(&pcs_params))
+            .filter(|&ring_size| ring_size > 0)
+            .ok_or(ark_serialize::SerializationError::InvalidData)?;
+        RingContextBuilder::new()
+            .ring_size(ring_size)
+            .pcs_params(pcs_params)
+            .build()
             .map_err(|_| ark_serialize::SerializationError::InvalidData)
     }
 }
@@ -309,66 +1002,1464 @@ where
     }
 }
 
-#[cfg(test)]
-pub(crate) mod testing {
-    use super::*;
-    use crate::{pedersen, testing as common};
+/// Asynchronous ring proof generation.
+///
+/// Implemented for every `VrfSigner` which can be moved to another thread.
+#[cfg(feature = "async")]
+pub trait AsyncProver<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Generate a ring proof on the tokio blocking thread pool.
+    ///
+    /// The returned future doesn't block the async executor. Cancellation via
+    /// `cancel` is honored between the proving phases (see `Prover::prove_with_progress`).
+    /// A panic of the proving task is propagated to the caller.
+    ///
+    /// # Panics
+    ///
+    /// If not called within a tokio runtime.
+    fn sign_async(
+        self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: Vec<u8>,
+        prover: ark_std::sync::Arc<RingProver<S, P>>,
+        cancel: CancellationToken,
+    ) -> SignTask<S, P>;
+}
 
-    pub const TEST_RING_SIZE: usize = 8;
+#[cfg(feature = "async")]
+impl<S, P, T> AsyncProver<S, P> for T
+where
+    S: RingSuite + 'static,
+    P: PCS<BaseField<S>> + 'static,
+    T: VrfSigner<S> + Send + 'static,
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+    RingProver<S, P>: Send + Sync,
+    Proof<S, P>: Send,
+{
+    fn sign_async(
+        self,
+        input: Input<S>,
+        output: Output<S>,
+        ad: Vec<u8>,
+        prover: ark_std::sync::Arc<RingProver<S, P>>,
+        cancel: CancellationToken,
+    ) -> SignTask<S, P> {
+        SignTask(tokio::task::spawn_blocking(move || {
+            self.prove_with_progress(input, output, ad, &prover, &(), &cancel)
+        }))
+    }
+}
 
-    #[allow(unused)]
-    pub fn prove_verify<S: RingSuite>()
-    where
-        BaseField<S>: ark_ff::PrimeField,
-        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
-        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
-    {
-        let rng = &mut ark_std::test_rng();
-        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+/// Ring proof generation task spawned by `AsyncProver::sign_async`.
+#[cfg(feature = "async")]
+pub struct SignTask<S: RingSuite, P: PCS<BaseField<S>> = Kzg<S>>(
+    tokio::task::JoinHandle<Result<Proof<S, P>, Error>>,
+)
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig;
 
-        let secret = Secret::<S>::from_seed(common::TEST_SEED);
-        let public = secret.public();
-        let input = Input::from(common::random_val(Some(rng)));
-        let output = secret.output(input);
+#[cfg(feature = "async")]
+impl<S: RingSuite, P: PCS<BaseField<S>>> core::future::Future for SignTask<S, P>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    type Output = Result<Proof<S, P>, Error>;
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        core::pin::Pin::new(&mut self.0).poll(cx).map(|res| match res {
+            Ok(res) => res,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(Error::Cancelled),
+        })
+    }
+}
 
-        let ring_size = ring_ctx.max_ring_size();
+/// Compressed serialization of a ring commitment.
+fn commitment_bytes<S: RingSuite>(commitment: &RingCommitment<S>) -> Vec<u8>
+where
+    BaseField<S>: ark_ff::PrimeField,
+{
+    let mut buf = Vec::with_capacity(commitment.compressed_size());
+    commitment
+        .serialize_compressed(&mut buf)
+        .expect("serialization to vec can't fail");
+    buf
+}
 
-        let prover_idx = 3;
-        let mut pks = common::random_vec::<AffinePoint<S>>(ring_size, Some(rng));
-        pks[prover_idx] = public.0;
+/// Ring verifier key delta.
+///
+/// Encodes only the ring slots changed between two rings (e.g. two consecutive
+/// epochs), together with the commitments of the base and of the resulting ring.
+///
+/// The delta is applied to the base ring keys to obtain the new ring keys and
+/// verifier key. The integrity of the result is checked against the commitment
+/// carried by the delta.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerifierKeyDelta<S: RingSuite>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    base: RingCommitment<S>,
+    len: u32,
+    changes: Vec<(u32, AffinePoint<S>)>,
+    commitment: RingCommitment<S>,
+}
 
-        let prover_key = ring_ctx.prover_key(&pks);
-        let prover = ring_ctx.prover(prover_key, prover_idx);
-        let proof = secret.prove(input, output, b"foo", &prover);
+#[allow(private_bounds)]
+impl<S: RingSuite> VerifierKeyDelta<S>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    /// Construct the delta transforming the `base` ring into the `ring`.
+    ///
+    /// Returns the errors of `RingContext::check_ring` for any of the rings, and
+    /// `Error::RingTooLarge` if the `ring` length doesn't fit in the `u32` used by
+    /// the delta encoding.
+    pub fn new(
+        ring_ctx: &RingContext<S>,
+        base: &[AffinePoint<S>],
+        ring: &[AffinePoint<S>],
+    ) -> Result<Self, Error> {
+        ring_ctx.check_ring(base)?;
+        ring_ctx.check_ring(ring)?;
+        let to_u32 = |value: usize| {
+            u32::try_from(value).map_err(|_| Error::RingTooLarge {
+                expected: u32::MAX as usize,
+                actual: ring.len(),
+            })
+        };
+        let len = to_u32(ring.len())?;
+        let changes = ring
+            .iter()
+            .enumerate()
+            .filter(|(i, pk)| base.get(*i) != Some(*pk))
+            .map(|(i, pk)| Ok((to_u32(i)?, *pk)))
+            .collect::<Result<_, Error>>()?;
+        Ok(Self {
+            base: ring_ctx.verifier_key(base).commitment(),
+            len,
+            changes,
+            commitment: ring_ctx.verifier_key(ring).commitment(),
+        })
+    }
 
-        let verifier_key = ring_ctx.verifier_key(&pks);
-        let verifier = ring_ctx.verifier(verifier_key);
-        let result = Public::verify(input, output, b"foo", &proof, &verifier);
-        assert!(result.is_ok());
+    /// Number of changed slots.
+    pub fn changes(&self) -> usize {
+        self.changes.len()
     }
 
-    /// Check that complement point is not in the prime subgroup.
+    /// Check if the delta applies to the ring with the given verifier key.
+    pub fn applies_to(&self, verifier_key: &VerifierKey<S>) -> bool {
+        commitment_bytes::<S>(&verifier_key.commitment()) == commitment_bytes::<S>(&self.base)
+    }
+
+    /// Apply the delta to the `base` ring keys.
     ///
-    /// This is a requirement for the correct working of ring-proof backend.
-    #[allow(unused)]
-    pub fn check_accumulator_base<S: RingSuite>()
-    where
-        BaseField<S>: ark_ff::PrimeField,
-        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
-        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
-    {
-        use utils::te_sw_map::SWMapping;
-        let pt = S::ACCUMULATOR_BASE.into_sw();
-        assert!(pt.is_on_curve());
-        assert!(!pt.is_in_correct_subgroup_assuming_on_curve());
+    /// Returns the new ring keys together with the new verifier key.
+    ///
+    /// Returns `Error::InvalidData` if a change is out of the new ring bounds or if
+    /// some new slot is not provided, the errors of `RingContext::check_ring` for
+    /// the new ring, and `Error::RingCommitmentMismatch` if the resulting ring
+    /// commitment doesn't match the one carried by the delta (e.g. `base` is not
+    /// the expected ring).
+    pub fn apply(
+        &self,
+        ring_ctx: &RingContext<S>,
+        base: &[AffinePoint<S>],
+    ) -> Result<(Vec<AffinePoint<S>>, VerifierKey<S>), Error> {
+        let len = self.len as usize;
+        ring_ctx.check_ring_size(len)?;
+        let mut ring: Vec<_> = base.iter().take(len).map(|pk| Some(*pk)).collect();
+        ring.resize(len, None);
+        for (i, pk) in self.changes.iter() {
+            *ring.get_mut(*i as usize).ok_or(Error::InvalidData)? = Some(*pk);
+        }
+        let ring = ring
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::InvalidData)?;
+        let verifier_key = ring_ctx.try_verifier_key(&ring)?;
+        if commitment_bytes::<S>(&verifier_key.commitment())
+            != commitment_bytes::<S>(&self.commitment)
+        {
+            return Err(Error::RingCommitmentMismatch);
+        }
+        Ok((ring, verifier_key))
     }
+}
 
-    #[macro_export]
-    macro_rules! ring_suite_tests {
-        ($suite:ident) => {
-            #[test]
-            fn ring_prove_verify() {
-                $crate::ring::testing::prove_verify::<$suite>()
+/// Builder for `RingContext`.
+///
+/// Validates the KZG SRS degree against the evaluation domain required by the ring
+/// size and the consistency of the resulting PIOP parameters.
+pub struct RingContextBuilder<S: RingSuite>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+{
+    ring_size: Option<usize>,
+    pcs_params: Option<PcsParams<S>>,
+}
+
+impl<S: RingSuite> Default for RingContextBuilder<S>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+{
+    fn default() -> Self {
+        Self {
+            ring_size: None,
+            pcs_params: None,
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<S: RingSuite> RingContextBuilder<S>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    /// Construct a new empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ring size to be managed by the context.
+    pub fn ring_size(mut self, ring_size: usize) -> Self {
+        self.ring_size = Some(ring_size);
+        self
+    }
+
+    /// KZG SRS (powers of tau).
+    pub fn pcs_params(mut self, pcs_params: PcsParams<S>) -> Self {
+        self.pcs_params = Some(pcs_params);
+        self
+    }
+
+    /// Build the `RingContext`.
+    ///
+    /// Returns `Error::InvalidData` if some parameter is missing or if the ring size is
+    /// zero, and `Error::RingTooLarge` if the requested ring size exceeds the max ring
+    /// size supported by the SRS.
+    pub fn build(self) -> Result<RingContext<S>, Error> {
+        let ring_size = self
+            .ring_size
+            .filter(|&ring_size| ring_size > 0)
+            .ok_or(Error::InvalidData)?;
+        let pcs_params = self.pcs_params.ok_or(Error::InvalidData)?;
+        let max_ring_size = srs_max_ring_size::<S>(&pcs_params);
+        if ring_size > max_ring_size {
+            return Err(Error::RingTooLarge {
+                expected: max_ring_size,
+                actual: ring_size,
+            });
+        }
+        let ctx = RingContext::from_srs(ring_size, pcs_params)?;
+        ctx.check_ring_size(ring_size)?;
+        Ok(ctx)
+    }
+}
+
+/// Ring managed by `RingsManager`.
+struct ManagedRing<S: RingSuite>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    pks: Vec<AffinePoint<S>>,
+    commitment: RingCommitment<S>,
+    last_used: u64,
+}
+
+/// Manager of multiple rings sharing the same `RingContext`.
+///
+/// Owns a single set of PCS and PIOP parameters and keeps track of a bounded number
+/// of rings, each identified by an application defined id (e.g. epoch or shard index).
+///
+/// Only the ring keys and the (constant size) ring commitment are retained for each
+/// ring. Verifier keys are cheaply reconstructed from the commitment, while prover
+/// keys are constructed on demand.
+///
+/// When the capacity is reached, the least recently used ring is evicted.
+pub struct RingsManager<S: RingSuite, K: Ord + Clone>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+{
+    ctx: RingContext<S>,
+    rings: ark_std::collections::BTreeMap<K, ManagedRing<S>>,
+    // Rings ids by last use time, least recently used first.
+    lru: ark_std::collections::BTreeMap<u64, K>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl<S: RingSuite, K: Ord + Clone> RingsManager<S, K>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    /// Construct a new manager retaining at most `capacity` rings.
+    ///
+    /// A zero capacity is treated as one.
+    pub fn new(ctx: RingContext<S>, capacity: usize) -> Self {
+        Self {
+            ctx,
+            rings: Default::default(),
+            lru: Default::default(),
+            capacity: capacity.max(1),
+            clock: 0,
+        }
+    }
+
+    /// Shared ring context.
+    #[inline(always)]
+    pub fn context(&self) -> &RingContext<S> {
+        &self.ctx
+    }
+
+    /// Number of managed rings.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.rings.len()
+    }
+
+    /// Returns `true` if there are no managed rings.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.rings.is_empty()
+    }
+
+    /// Returns `true` if the ring identified by `id` is managed.
+    pub fn contains(&self, id: &K) -> bool {
+        self.rings.contains_key(id)
+    }
+
+    /// Register the ring identified by `id`, replacing any previous ring with the same id.
+    ///
+    /// Note: if `pks.len() > self.context().max_ring_size()` the extra keys in the tail are ignored.
+    ///
+    /// Returns the id of the ring evicted to make room for the new one, if any.
+    /// Returns `Error::InvalidPublicKey` if some key can't be used in a ring
+    /// (see `RingContext::check_ring`).
+    pub fn insert(&mut self, id: K, pks: &[AffinePoint<S>]) -> Result<Option<K>, Error> {
+        let pks = pks[..pks.len().min(self.ctx.max_ring_size())].to_vec();
+        let commitment = self.ctx.try_verifier_key(&pks)?.commitment();
+        let evicted = match self.rings.get(&id) {
+            Some(ring) => {
+                self.lru.remove(&ring.last_used);
+                None
+            }
+            None if self.rings.len() >= self.capacity => self.evict(),
+            None => None,
+        };
+        let last_used = self.tick();
+        self.lru.insert(last_used, id.clone());
+        self.rings.insert(
+            id,
+            ManagedRing {
+                pks,
+                commitment,
+                last_used,
+            },
+        );
+        Ok(evicted)
+    }
+
+    /// Stop managing the ring identified by `id`.
+    ///
+    /// Returns `true` if the ring was managed.
+    pub fn remove(&mut self, id: &K) -> bool {
+        match self.rings.remove(id) {
+            Some(ring) => self.lru.remove(&ring.last_used).is_some(),
+            None => false,
+        }
+    }
+
+    /// Keys of the ring identified by `id`.
+    pub fn ring(&self, id: &K) -> Option<&[AffinePoint<S>]> {
+        self.rings.get(id).map(|ring| &ring.pks[..])
+    }
+
+    /// Commitment of the ring identified by `id`.
+    pub fn commitment(&self, id: &K) -> Option<&RingCommitment<S>> {
+        self.rings.get(id).map(|ring| &ring.commitment)
+    }
+
+    /// Construct the `ProverKey` for the ring identified by `id`.
+    pub fn prover_key(&mut self, id: &K) -> Option<ProverKey<S>> {
+        self.touch(id)?;
+        let ring = self.rings.get(id)?;
+        Some(self.ctx.prover_key(&ring.pks))
+    }
+
+    /// Construct the `RingProver` for the ring identified by `id` and the prover
+    /// implied by `key_index`.
+    pub fn prover(&mut self, id: &K, key_index: usize) -> Option<RingProver<S>> {
+        let prover_key = self.prover_key(id)?;
+        Some(self.ctx.prover_from_owned_key(prover_key, key_index))
+    }
+
+    /// Construct the `VerifierKey` for the ring identified by `id`.
+    pub fn verifier_key(&mut self, id: &K) -> Option<VerifierKey<S>> {
+        self.touch(id)?;
+        let commitment = self.rings.get(id)?.commitment.clone();
+        Some(self.ctx.verifier_key_from_commitment(commitment))
+    }
+
+    /// Construct the `RingVerifier` for the ring identified by `id`.
+    pub fn verifier(&mut self, id: &K) -> Option<RingVerifier<S>> {
+        let verifier_key = self.verifier_key(id)?;
+        Some(self.ctx.verifier(verifier_key))
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn touch(&mut self, id: &K) -> Option<()> {
+        let now = self.tick();
+        let ring = self.rings.get_mut(id)?;
+        self.lru.remove(&ring.last_used);
+        ring.last_used = now;
+        self.lru.insert(now, id.clone());
+        Some(())
+    }
+
+    fn evict(&mut self) -> Option<K> {
+        let (_, id) = self.lru.pop_first()?;
+        self.rings.remove(&id);
+        Some(id)
+    }
+}
+
+/// Verifier keys cache.
+///
+/// Maps the hash of a ring keys list to the corresponding `VerifierKey`, so that
+/// repeated lookups for the same ring don't require to recompute the ring commitment.
+///
+/// When the capacity is reached, the least recently used key is evicted.
+pub struct VerifierKeyCache<S: RingSuite>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    keys: ark_std::collections::BTreeMap<Vec<u8>, (VerifierKey<S>, u64)>,
+    // Rings hashes by last use time, least recently used first.
+    lru: ark_std::collections::BTreeMap<u64, Vec<u8>>,
+    capacity: usize,
+    clock: u64,
+}
+
+#[allow(private_bounds)]
+impl<S: RingSuite> VerifierKeyCache<S>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig + Clone,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    /// Construct a new cache holding at most `capacity` verifier keys (min 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            keys: Default::default(),
+            lru: Default::default(),
+            capacity: capacity.max(1),
+            clock: 0,
+        }
+    }
+
+    /// Number of cached verifier keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Drop all the cached verifier keys.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.lru.clear();
+    }
+
+    /// Get the `VerifierKey` for the given ring.
+    ///
+    /// The key is constructed via `ring_ctx` and cached on miss.
+    ///
+    /// Returns the errors of `RingContext::try_verifier_key` on miss.
+    pub fn verifier_key(
+        &mut self,
+        ring_ctx: &RingContext<S>,
+        pks: &[AffinePoint<S>],
+    ) -> Result<VerifierKey<S>, Error> {
+        self.clock += 1;
+        let id = Self::ring_hash(pks);
+        if let Some((verifier_key, last_used)) = self.keys.get_mut(&id) {
+            let id = self.lru.remove(last_used).expect("cached keys are indexed");
+            *last_used = self.clock;
+            self.lru.insert(self.clock, id);
+            return Ok(verifier_key.clone());
+        }
+        let verifier_key = ring_ctx.try_verifier_key(pks)?;
+        if self.keys.len() >= self.capacity {
+            if let Some((_, lru)) = self.lru.pop_first() {
+                self.keys.remove(&lru);
+            }
+        }
+        self.lru.insert(self.clock, id.clone());
+        self.keys.insert(id, (verifier_key.clone(), self.clock));
+        Ok(verifier_key)
+    }
+
+    /// Hash of the ring keys list.
+    fn ring_hash(pks: &[AffinePoint<S>]) -> Vec<u8> {
+        const DOM_SEP: u8 = 0x52;
+        let mut buf = [S::SUITE_ID, &[DOM_SEP]].concat();
+        buf.extend_from_slice(&(pks.len() as u64).to_le_bytes());
+        pks.iter()
+            .for_each(|pk| S::Codec::point_encode(pk, &mut buf));
+        utils::hash::<S::Hasher>(&buf).to_vec()
+    }
+}
+
+/// Serialized ring artifacts envelope magic.
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"RVRF";
+
+/// Serialized ring artifacts envelope format version.
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Pairing identifier.
+///
+/// First 8 bytes of the suite hash of the compressed pairing G1 generator.
+pub fn pairing_id<S: RingSuite>() -> [u8; 8] {
+    use ark_ec::pairing::Pairing;
+    let mut buf = Vec::new();
+    <S::Pairing as Pairing>::G1Affine::generator()
+        .serialize_compressed(&mut buf)
+        .expect("serialization to vec can't fail");
+    let mut id = [0; 8];
+    id.copy_from_slice(&utils::hash::<S::Hasher>(&buf)[..8]);
+    id
+}
+
+/// Serialize a ring artifact (e.g. `RingContext`, keys, commitments, proofs) within
+/// a versioned envelope.
+///
+/// Envelope format:
+/// `magic (4) || version (1) || suite_id_len (1) || suite_id || pairing_id (8) || artifact`
+///
+/// The artifact is serialized using arkworks compressed format.
+pub fn envelope_encode<S: RingSuite, T: CanonicalSerialize>(artifact: &T) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(
+        ENVELOPE_MAGIC.len() + 2 + S::SUITE_ID.len() + 8 + artifact.compressed_size(),
+    );
+    buf.extend_from_slice(&ENVELOPE_MAGIC);
+    buf.push(ENVELOPE_VERSION);
+    buf.push(utils::LenPrefix::<S>::SUITE_ID);
+    buf.extend_from_slice(S::SUITE_ID);
+    buf.extend_from_slice(&pairing_id::<S>());
+    artifact
+        .serialize_compressed(&mut buf)
+        .expect("serialization to vec can't fail");
+    buf
+}
+
+/// Deserialize a ring artifact previously serialized with `envelope_encode`.
+///
+/// Returns `Error::IncompatibleFormat` if the envelope magic, version or suite
+/// identifier doesn't match, `Error::SrsMismatch` if the pairing identifier
+/// doesn't match, and `Error::InvalidEncoding` if the artifact itself is not valid.
+pub fn envelope_decode<S: RingSuite, T: CanonicalDeserialize>(buf: &[u8]) -> Result<T, Error> {
+    let (magic, buf) = envelope::split_at_checked(buf, ENVELOPE_MAGIC.len())?;
+    let (version, buf) = envelope::split_at_checked(buf, 1)?;
+    if magic != ENVELOPE_MAGIC || version[0] != ENVELOPE_VERSION {
+        return Err(Error::IncompatibleFormat);
+    }
+    let (suite_id_len, buf) = envelope::split_at_checked(buf, 1)?;
+    let (suite_id, buf) = envelope::split_at_checked(buf, suite_id_len[0] as usize)?;
+    let (pairing, mut buf) = envelope::split_at_checked(buf, 8)?;
+    if suite_id != S::SUITE_ID {
+        return Err(Error::IncompatibleFormat);
+    }
+    if pairing != pairing_id::<S>() {
+        return Err(Error::SrsMismatch);
+    }
+    T::deserialize_compressed(&mut buf).map_err(Into::into)
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::{pedersen, testing as common};
+
+    pub const TEST_RING_SIZE: usize = 8;
+
+    /// Ring fixture shared by the tests.
+    ///
+    /// A ring of `TEST_RING_SIZE` random keys, with the key of the `TEST_SEED` secret
+    /// at `prover_idx`, and a random input together with the secret output.
+    pub struct RingFixture<S: RingSuite>
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+    {
+        pub ring_ctx: RingContext<S>,
+        pub secret: Secret<S>,
+        pub input: Input<S>,
+        pub output: Output<S>,
+        pub pks: Vec<AffinePoint<S>>,
+        pub prover_idx: usize,
+    }
+
+    impl<S: RingSuite> RingFixture<S>
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        pub fn new(rng: &mut impl ark_std::rand::RngCore) -> Self {
+            let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+            let secret = Secret::<S>::from_seed(common::TEST_SEED);
+            let input = Input::from(common::random_val(Some(rng)));
+            let output = secret.output(input);
+
+            let prover_idx = 3;
+            let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+            pks[prover_idx] = secret.public().0;
+
+            Self {
+                ring_ctx,
+                secret,
+                input,
+                output,
+                pks,
+                prover_idx,
+            }
+        }
+    }
+
+    #[allow(unused)]
+    pub fn prove_verify<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+        let input = Input::from(common::random_val(Some(rng)));
+        let output = secret.output(input);
+
+        let ring_size = ring_ctx.max_ring_size();
+
+        let prover_idx = 3;
+        let mut pks = common::random_vec::<AffinePoint<S>>(ring_size, Some(rng));
+        pks[prover_idx] = public.0;
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let proof = secret.prove(input, output, b"foo", &prover);
+
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+
+        let checked = secret.prove_checked(input, output, b"foo", &prover).unwrap();
+        let result = Public::verify(input, output, b"foo", &checked, &verifier);
+        assert!(result.is_ok());
+        let other = Secret::<S>::from_seed(b"other").output(input);
+        assert!(matches!(
+            secret.prove_checked(input, other, b"foo", &prover),
+            Err(Error::VerificationFailure)
+        ));
+
+        // Transcript is bound to the ring, proof can't be replayed against another ring.
+        pks.swap(0, 1);
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_err());
+        pks.swap(0, 1);
+
+        // Proof is rejected by a verifier using a different transcript label.
+        let verifier = RingVerifier::<S>::init(
+            ring_ctx.verifier_key(&pks),
+            ring_ctx.piop_params.clone(),
+            ring_proof::Transcript::new(b"other-suite"),
+        );
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(matches!(result, Err(Error::RingProofFailure)));
+    }
+
+    #[allow(unused)]
+    pub fn prove_with_rng<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        use ark_std::rand::SeedableRng;
+
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+
+        let crypto_rng = &mut rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+        let proof1 = secret.prove_with_rng(input, output, b"foo", &prover, crypto_rng);
+        let proof2 = secret.prove_with_rng(input, output, b"foo", &prover, crypto_rng);
+        // Different blinding factors.
+        assert_ne!(proof1.key_commitment(), proof2.key_commitment());
+
+        for proof in [proof1, proof2] {
+            let result = Public::verify(input, output, b"foo", &proof, &verifier);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[allow(unused)]
+    pub fn shared_prover_key<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        use ark_std::sync::Arc;
+
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secrets: Vec<_> = (0..2u8).map(|i| Secret::<S>::from_seed(&[i])).collect();
+        let input = Input::from(common::random_val(Some(rng)));
+
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        pks[1] = secrets[0].public().0;
+        pks[5] = secrets[1].public().0;
+
+        let prover_key = Arc::new(ring_ctx.prover_key(&pks));
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+
+        for (secret, idx) in secrets.iter().zip([1, 5]) {
+            let prover = ring_ctx.prover(&prover_key, idx);
+            let output = secret.output(input);
+            let proof = secret.prove(input, output, b"foo", &prover);
+            let result = Public::verify(input, output, b"foo", &proof, &verifier);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[allow(unused)]
+    pub fn prove_verify_weighted<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let public = secret.public();
+        let input = Input::from(common::random_val(Some(rng)));
+        let output = secret.output(input);
+
+        let pks = common::random_vec::<AffinePoint<S>>(3, Some(rng));
+        let members = [(pks[0], 2), (pks[1], 0), (public.0, 3), (pks[2], 1)];
+        assert_eq!(total_weight::<S>(&members), Some(6));
+
+        let slots = ring_ctx.weighted_ring(&members).unwrap();
+        assert_eq!(slots.len(), 6);
+        assert_eq!(weighted_key_index::<S>(&members, 1), None);
+        let prover_idx = weighted_key_index::<S>(&members, 2).unwrap();
+        assert_eq!(prover_idx, 2);
+        assert_eq!(slots[prover_idx], public.0);
+
+        let prover_key = ring_ctx.prover_key(&slots);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let proof = secret.prove(input, output, b"foo", &prover);
+
+        let verifier_key = ring_ctx.verifier_key(&slots);
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+
+        let too_heavy = [(public.0, ring_ctx.max_ring_size() + 1)];
+        assert!(ring_ctx.weighted_ring(&too_heavy).is_err());
+    }
+
+    #[allow(unused)]
+    pub fn rings_manager<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+        let mut manager = RingsManager::<S, u32>::new(ring_ctx, 2);
+
+        assert_eq!(manager.insert(0, &pks), Ok(None));
+        assert_eq!(manager.insert(1, &pks[..4]), Ok(None));
+        // Ring 0 becomes the most recently used.
+        let prover = manager.prover(&0, prover_idx).unwrap();
+        assert_eq!(manager.insert(2, &pks[2..]), Ok(Some(1)));
+        assert_eq!(manager.len(), 2);
+        assert!(!manager.contains(&1));
+        assert!(manager.verifier(&1).is_none());
+
+        let proof = secret.prove(input, output, b"foo", &prover);
+        let verifier = manager.verifier(&0).unwrap();
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+
+        // Proof doesn't verify against a different ring.
+        let verifier = manager.verifier(&2).unwrap();
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_err());
+
+        assert!(manager.remove(&0));
+        assert!(!manager.remove(&0));
+        assert_eq!(manager.len(), 1);
+
+        // Re-inserting a managed ring doesn't evict.
+        assert_eq!(manager.insert(3, &pks[..4]), Ok(None));
+        assert_eq!(manager.insert(3, &pks[..5]), Ok(None));
+        assert_eq!(manager.ring(&3).map(|ring| ring.len()), Some(5));
+        // Ring 2 becomes the most recently used.
+        assert!(manager.verifier_key(&2).is_some());
+        assert_eq!(manager.insert(4, &pks[..4]), Ok(Some(3)));
+        assert_eq!(manager.insert(5, &pks[..4]), Ok(Some(2)));
+    }
+
+    #[allow(unused)]
+    pub fn context_builder<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+        let pcs_params = ring_ctx.pcs_params.clone();
+
+        let ctx = RingContextBuilder::<S>::new()
+            .ring_size(TEST_RING_SIZE)
+            .pcs_params(pcs_params.clone())
+            .build()
+            .unwrap();
+        assert_eq!(ctx.max_ring_size(), ring_ctx.max_ring_size());
+
+        // Missing parameters
+        assert!(RingContextBuilder::<S>::new()
+            .ring_size(TEST_RING_SIZE)
+            .build()
+            .is_err());
+        assert!(RingContextBuilder::<S>::new()
+            .pcs_params(pcs_params.clone())
+            .build()
+            .is_err());
+
+        // Empty ring
+        assert!(RingContextBuilder::<S>::new()
+            .ring_size(0)
+            .pcs_params(pcs_params.clone())
+            .build()
+            .is_err());
+
+        // Ring size exceeding the one supported by the SRS
+        let max_ring_size = ring_ctx.max_ring_size();
+        let res = RingContextBuilder::<S>::new()
+            .ring_size(max_ring_size + 1)
+            .pcs_params(pcs_params.clone())
+            .build();
+        assert!(matches!(
+            res,
+            Err(Error::RingTooLarge { expected, actual })
+                if expected == max_ring_size && actual == max_ring_size + 1
+        ));
+        let res = RingContextBuilder::<S>::new()
+            .ring_size(usize::MAX)
+            .pcs_params(pcs_params.clone())
+            .build();
+        assert!(matches!(res, Err(Error::RingTooLarge { .. })));
+
+        // SRS too small for the required domain
+        let res = RingContext::<S>::from_srs(2 * domain_size::<S>(TEST_RING_SIZE), pcs_params);
+        assert!(matches!(res, Err(Error::SrsTooSmall { .. })));
+
+        // Serialization round trip
+        let mut buf = Vec::new();
+        ctx.serialize_compressed(&mut buf).unwrap();
+        let ctx2 = RingContext::<S>::deserialize_compressed(&mut &buf[..]).unwrap();
+        assert_eq!(ctx2.max_ring_size(), ctx.max_ring_size());
+        let mut buf2 = Vec::new();
+        ctx2.serialize_compressed(&mut buf2).unwrap();
+        assert_eq!(buf, buf2);
+    }
+
+    #[allow(unused)]
+    pub fn ring_root<S: RingSuite, const N: usize>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let proof = secret.prove(input, output, b"foo", &prover);
+
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let root = ring_ctx.ring_root::<N>(&verifier_key).unwrap();
+        assert!(ring_ctx.ring_root::<1>(&verifier_key).is_err());
+
+        let verifier_key = ring_ctx.verifier_key_from_ring_root(&root).unwrap();
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+    }
+
+    #[allow(unused)]
+    pub fn key_index<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let public = Secret::<S>::from_seed(common::TEST_SEED).public();
+        let pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        assert_eq!(super::key_index::<S>(&pks, &public.0), None);
+        for i in 0..TEST_RING_SIZE {
+            let mut pks = pks.clone();
+            pks[i] = public.0;
+            assert_eq!(super::key_index::<S>(&pks, &public.0), Some(i));
+        }
+        assert_eq!(super::key_index::<S>(&[], &public.0), None);
+    }
+
+    #[allow(unused)]
+    pub fn sizes<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let proof = secret.prove(input, output, b"foo", &prover);
+        let verifier_key = ring_ctx.verifier_key(&pks);
+
+        let sizes = ring_ctx.sizes();
+        assert_eq!(sizes.pcs_params, ring_ctx.compressed_size());
+        assert_eq!(
+            sizes.ring_commitment,
+            verifier_key.commitment().compressed_size()
+        );
+        assert_eq!(sizes.verifier_key, verifier_key.compressed_size());
+        assert_eq!(sizes.pedersen_proof, proof.pedersen_proof.compressed_size());
+        assert_eq!(sizes.ring_proof, proof.ring_proof.compressed_size());
+        assert_eq!(sizes.proof, proof.compressed_size());
+
+        let buf: Vec<u8> = proof.into();
+        assert_eq!(buf.len(), sizes.proof);
+        let proof = Proof::<S>::try_from(&buf[..]).unwrap();
+        let verifier = ring_ctx.verifier(verifier_key);
+        assert!(Public::verify(input, output, b"foo", &proof, &verifier).is_ok());
+        let mut extra = buf.clone();
+        extra.push(0);
+        assert!(matches!(
+            Proof::<S>::try_from(&extra[..]),
+            Err(Error::InvalidEncoding)
+        ));
+    }
+
+    #[allow(unused)]
+    pub fn linkability_tag<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+
+        let (proof1, tag1) = secret.prove_linkable(input, output, b"foo", &prover);
+        let (proof2, tag2) = secret.prove_linkable(input, output, b"bar", &prover);
+        assert_eq!(tag1, tag2);
+
+        let result = Public::verify_linkable(input, output, b"foo", &proof1, &verifier, &tag1);
+        assert!(result.is_ok());
+        let result = Public::verify_linkable(input, output, b"bar", &proof2, &verifier, &tag2);
+        assert!(result.is_ok());
+
+        // Different input gives an unlinkable tag.
+        let input2 = Input::from(common::random_val(Some(rng)));
+        let output2 = secret.output(input2);
+        let (proof3, tag3) = secret.prove_linkable(input2, output2, b"foo", &prover);
+        assert_ne!(tag1, tag3);
+
+        // Tag not matching the output.
+        let result = Public::verify_linkable(input2, output2, b"foo", &proof3, &verifier, &tag1);
+        assert_eq!(result, Err(Error::LinkabilityTagMismatch));
+    }
+
+    #[allow(unused)]
+    pub fn verifier_key_delta<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let base = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let mut ring = base.clone();
+        ring[2] = common::random_val(Some(rng));
+        ring[6] = common::random_val(Some(rng));
+        ring.truncate(TEST_RING_SIZE - 1);
+
+        let delta = VerifierKeyDelta::new(&ring_ctx, &base, &ring).unwrap();
+        assert_eq!(delta.changes(), 2);
+        assert!(delta.applies_to(&ring_ctx.verifier_key(&base)));
+        assert!(!delta.applies_to(&ring_ctx.verifier_key(&ring)));
+
+        let mut buf = Vec::new();
+        delta.serialize_compressed(&mut buf).unwrap();
+        let delta = VerifierKeyDelta::<S>::deserialize_compressed(&mut &buf[..]).unwrap();
+
+        let (new_ring, verifier_key) = delta.apply(&ring_ctx, &base).unwrap();
+        assert_eq!(new_ring, ring);
+        assert_eq!(
+            commitment_bytes::<S>(&verifier_key.commitment()),
+            commitment_bytes::<S>(&ring_ctx.verifier_key(&ring).commitment())
+        );
+
+        // Applied to the wrong base ring.
+        let mut wrong = base.clone();
+        wrong[0] = common::random_val(Some(rng));
+        assert!(delta.apply(&ring_ctx, &wrong).is_err());
+
+        // Missing new slots.
+        assert!(delta.apply(&ring_ctx, &base[..4]).is_err());
+    }
+
+    #[allow(unused)]
+    pub fn capacity_checks<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+        let max = ring_ctx.max_ring_size();
+
+        let pks = common::random_vec::<AffinePoint<S>>(max + 1, Some(rng));
+        let res = ring_ctx.try_verifier_key(&pks);
+        assert!(matches!(
+            res,
+            Err(Error::RingTooLarge { expected, actual }) if expected == max && actual == max + 1
+        ));
+        assert!(ring_ctx.try_prover_key(&pks).is_err());
+
+        let pks = &pks[..TEST_RING_SIZE];
+        let prover_key = ring_ctx.try_prover_key(pks).unwrap();
+        let res = ring_ctx.try_prover(&prover_key, TEST_RING_SIZE, TEST_RING_SIZE);
+        assert!(matches!(
+            res,
+            Err(Error::KeyIndexOutOfBounds { expected, actual })
+                if expected == TEST_RING_SIZE && actual == TEST_RING_SIZE
+        ));
+        assert!(ring_ctx
+            .try_prover(&prover_key, TEST_RING_SIZE, TEST_RING_SIZE - 1)
+            .is_ok());
+
+        // Keys without a SW counterpart (e.g. the TE identity) are rejected.
+        let mut pks = pks.to_vec();
+        pks[0] = AffinePoint::<S>::zero();
+        if utils::te_sw_map::SWMapping::try_into_sw(pks[0]).is_none() {
+            assert_eq!(ring_ctx.check_ring(&pks), Err(Error::InvalidPublicKey));
+            assert!(ring_ctx.try_verifier_key(&pks).is_err());
+        }
+    }
+
+    #[allow(unused)]
+    pub fn verifier_key_cache<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+        let mut cache = VerifierKeyCache::<S>::new(2);
+        assert!(cache.is_empty());
+
+        let rings: Vec<_> = (0..3)
+            .map(|_| common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng)))
+            .collect();
+        let expected: Vec<_> = rings
+            .iter()
+            .map(|pks| commitment_bytes::<S>(&ring_ctx.verifier_key(pks).commitment()))
+            .collect();
+        let cached = |cache: &mut VerifierKeyCache<S>, i: usize| {
+            commitment_bytes::<S>(
+                &cache
+                    .verifier_key(&ring_ctx, &rings[i])
+                    .unwrap()
+                    .commitment(),
+            )
+        };
+
+        assert_eq!(cached(&mut cache, 0), expected[0]);
+        assert_eq!(cached(&mut cache, 1), expected[1]);
+        // Hit
+        assert_eq!(cached(&mut cache, 0), expected[0]);
+        assert_eq!(cache.len(), 2);
+        // Evicts ring 1 (least recently used)
+        assert_eq!(cached(&mut cache, 2), expected[2]);
+        assert_eq!(cache.len(), 2);
+        assert!(cache
+            .keys
+            .contains_key(&VerifierKeyCache::<S>::ring_hash(&rings[0])));
+        assert!(!cache
+            .keys
+            .contains_key(&VerifierKeyCache::<S>::ring_hash(&rings[1])));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[allow(unused)]
+    pub fn envelope<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+        let pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        let commitment = ring_ctx.verifier_key(&pks).commitment();
+
+        let buf = envelope_encode::<S, _>(&commitment);
+        let decoded = envelope_decode::<S, RingCommitment<S>>(&buf).unwrap();
+        assert_eq!(
+            envelope_encode::<S, _>(&decoded),
+            buf,
+            "envelope round trip mismatch"
+        );
+
+        // Magic, version, suite id length and suite id mismatches.
+        let pairing_offset = ENVELOPE_MAGIC.len() + 2 + S::SUITE_ID.len();
+        for i in [0, 4, 5, 6] {
+            let mut bad = buf.clone();
+            bad[i] ^= 0x01;
+            let res = envelope_decode::<S, RingCommitment<S>>(&bad);
+            assert!(matches!(res, Err(Error::IncompatibleFormat)));
+        }
+
+        // Pairing id mismatch.
+        let mut bad = buf.clone();
+        bad[pairing_offset] ^= 0x01;
+        let res = envelope_decode::<S, RingCommitment<S>>(&bad);
+        assert!(matches!(res, Err(Error::SrsMismatch)));
+
+        // Truncated header.
+        let res = envelope_decode::<S, RingCommitment<S>>(&buf[..pairing_offset]);
+        assert!(matches!(res, Err(Error::IncompatibleFormat)));
+
+        // Truncated artifact.
+        let res = envelope_decode::<S, RingCommitment<S>>(&buf[..buf.len() - 1]);
+        assert!(matches!(res, Err(Error::InvalidEncoding)));
+    }
+
+    #[cfg(feature = "async")]
+    #[allow(unused)]
+    pub fn sign_async<S: RingSuite + 'static>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+        RingProver<S>: Send + Sync,
+        Proof<S>: Send,
+    {
+        use ark_std::sync::Arc;
+
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = Arc::new(ring_ctx.prover(&prover_key, prover_idx));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let cancel = CancellationToken::new();
+        let proof = runtime
+            .block_on(async {
+                secret
+                    .clone()
+                    .sign_async(input, output, b"foo".to_vec(), prover.clone(), cancel.clone())
+                    .await
+            })
+            .unwrap();
+
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+
+        cancel.cancel();
+        let result = runtime.block_on(async {
+            secret
+                .sign_async(input, output, b"foo".to_vec(), prover, cancel)
+                .await
+        });
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
+    #[allow(unused)]
+    pub fn prove_with_progress<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        use core::cell::RefCell;
+
+        let rng = &mut ark_std::test_rng();
+        let RingFixture {
+            ring_ctx,
+            secret,
+            input,
+            output,
+            pks,
+            prover_idx,
+        } = RingFixture::<S>::new(rng);
+
+        let prover_key = ring_ctx.prover_key(&pks);
+        let prover = ring_ctx.prover(&prover_key, prover_idx);
+
+        let events = RefCell::new(Vec::new());
+        let sink = |phase: ProvingPhase, percent: u8| events.borrow_mut().push((phase, percent));
+        let cancel = CancellationToken::new();
+        let proof = secret
+            .prove_with_progress(input, output, b"foo", &prover, &sink, &cancel)
+            .unwrap();
+        assert_eq!(
+            events.into_inner(),
+            [
+                (ProvingPhase::Pedersen, 0),
+                (ProvingPhase::Pedersen, 100),
+                (ProvingPhase::Ring, 0),
+                (ProvingPhase::Ring, 100),
+            ]
+        );
+
+        let verifier_key = ring_ctx.verifier_key(&pks);
+        let verifier = ring_ctx.verifier(verifier_key);
+        let result = Public::verify(input, output, b"foo", &proof, &verifier);
+        assert!(result.is_ok());
+
+        // Cancellation requested via a token clone is honored.
+        cancel.clone().cancel();
+        let result = secret.prove_with_progress(input, output, b"foo", &prover, &(), &cancel);
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
+    /// Check that complement point is not in the prime subgroup.
+    ///
+    /// This is a requirement for the correct working of ring-proof backend.
+    #[allow(unused)]
+    pub fn check_accumulator_base<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: ark_ec::short_weierstrass::SWCurveConfig + Clone,
+        AffinePoint<S>: utils::te_sw_map::SWMapping<CurveConfig<S>>,
+    {
+        use utils::te_sw_map::SWMapping;
+        let pt = S::ACCUMULATOR_BASE.into_sw();
+        assert!(pt.is_on_curve());
+        assert!(!pt.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[macro_export]
+    macro_rules! ring_suite_tests {
+        ($suite:ident) => {
+            #[test]
+            fn ring_prove_verify() {
+                $crate::ring::testing::prove_verify::<$suite>()
+            }
+
+            #[test]
+            fn ring_prove_with_rng() {
+                $crate::ring::testing::prove_with_rng::<$suite>()
+            }
+
+            #[test]
+            fn ring_shared_prover_key() {
+                $crate::ring::testing::shared_prover_key::<$suite>()
+            }
+
+            #[test]
+            fn ring_prove_verify_weighted() {
+                $crate::ring::testing::prove_verify_weighted::<$suite>()
+            }
+
+            #[test]
+            fn ring_rings_manager() {
+                $crate::ring::testing::rings_manager::<$suite>()
+            }
+
+            #[test]
+            fn ring_context_builder() {
+                $crate::ring::testing::context_builder::<$suite>()
+            }
+
+            #[test]
+            fn ring_key_index() {
+                $crate::ring::testing::key_index::<$suite>()
+            }
+
+            #[test]
+            fn ring_sizes() {
+                $crate::ring::testing::sizes::<$suite>()
+            }
+
+            #[test]
+            fn ring_linkability_tag() {
+                $crate::ring::testing::linkability_tag::<$suite>()
+            }
+
+            #[test]
+            fn ring_verifier_key_delta() {
+                $crate::ring::testing::verifier_key_delta::<$suite>()
+            }
+
+            #[test]
+            fn ring_capacity_checks() {
+                $crate::ring::testing::capacity_checks::<$suite>()
+            }
+
+            #[test]
+            fn ring_verifier_key_cache() {
+                $crate::ring::testing::verifier_key_cache::<$suite>()
+            }
+
+            #[test]
+            fn ring_tickets_claim_verify() {
+                $crate::ring::tickets::testing::claim_verify::<$suite>()
+            }
+
+            #[test]
+            fn ring_envelope() {
+                $crate::ring::testing::envelope::<$suite>()
+            }
+
+            #[cfg(feature = "async")]
+            #[test]
+            fn ring_sign_async() {
+                $crate::ring::testing::sign_async::<$suite>()
+            }
+
+            #[test]
+            fn ring_prove_with_progress() {
+                $crate::ring::testing::prove_with_progress::<$suite>()
+            }
+
+            #[test]
+            fn ring_types_are_send_sync() {
+                fn assert_send_sync<T: Send + Sync>() {}
+                assert_send_sync::<$crate::ring::RingContext<$suite>>();
+                assert_send_sync::<$crate::ring::ProverKey<$suite>>();
+                assert_send_sync::<$crate::ring::VerifierKey<$suite>>();
+                assert_send_sync::<$crate::ring::RingProver<$suite>>();
+                assert_send_sync::<$crate::ring::RingVerifier<$suite>>();
+                assert_send_sync::<$crate::ring::Proof<$suite>>();
             }
 
             #[test]
@@ -438,7 +2529,7 @@ pub(crate) mod testing {
             ring_pks[prover_idx] = public.0;
 
             let prover_key = ring_ctx.prover_key(&ring_pks);
-            let prover = ring_ctx.prover(prover_key, prover_idx);
+            let prover = ring_ctx.prover(&prover_key, prover_idx);
             let proof = secret.prove(input, output, ad, &prover);
 
             let verifier_key = ring_ctx.verifier_key(&ring_pks);
@@ -498,7 +2589,7 @@ pub(crate) mod testing {
             let prover_idx = self.ring_pks.iter().position(|&pk| pk == public.0).unwrap();
 
             let prover_key = ring_ctx.prover_key(&self.ring_pks);
-            let prover = ring_ctx.prover(prover_key, prover_idx);
+            let prover = ring_ctx.prover(&prover_key, prover_idx);
 
             let verifier_key = ring_ctx.verifier_key(&self.ring_pks);
             let verifier = ring_ctx.verifier(verifier_key);
@@ -0,0 +1,184 @@
+//! Epoch tickets for Sassafras/Safrole-style protocols.
+//!
+//! Each ring member can claim up to `attempts` tickets per epoch. The ticket for
+//! an attempt is the Ring VRF output for an input derived from the epoch randomness
+//! and the attempt index. The ticket is valid if its identifier (score) is below
+//! a threshold which bounds the expected number of tickets per epoch.
+//!
+//! The ticket proof reveals that the ticket was claimed by a ring member without
+//! revealing which one.
+
+use super::*;
+
+/// Ticket identifier (score).
+pub type TicketId = u128;
+
+/// Ticket VRF input domain separator.
+pub const TICKET_INPUT_DOM: &[u8] = b"ark-ec-vrfs-ticket";
+
+/// Ticket claimed by a ring member.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Ticket<S: RingSuite>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Attempt index.
+    pub attempt: u32,
+    /// VRF output.
+    pub output: Output<S>,
+    /// Ring VRF proof.
+    pub proof: Proof<S>,
+}
+
+impl<S: RingSuite> Ticket<S>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    /// Ticket identifier.
+    pub fn id(&self) -> TicketId {
+        ticket_id(&self.output)
+    }
+}
+
+/// Ticket VRF input for the given epoch randomness and attempt index.
+///
+/// `None` is returned only if the suite hash-to-curve procedure fails.
+pub fn ticket_input<S: RingSuite>(randomness: &[u8], attempt: u32) -> Option<Input<S>> {
+    let data = [TICKET_INPUT_DOM, randomness, &attempt.to_le_bytes()].concat();
+    Input::new(&data)
+}
+
+/// Ticket identifier from the VRF output.
+///
+/// First 16 bytes of the output hash, interpreted as little endian integer.
+pub fn ticket_id<S: Suite>(output: &Output<S>) -> TicketId {
+    let hash = output.hash();
+    let mut buf = [0; 16];
+    buf.copy_from_slice(&hash[..16]);
+    TicketId::from_le_bytes(buf)
+}
+
+/// Tickets threshold.
+///
+/// Computed such that, on average, `redundancy * slots` tickets are below the
+/// threshold when each of the `validators` claims `attempts` tickets.
+///
+/// `T = (redundancy * slots) / (attempts * validators) * TicketId::MAX`
+pub fn ticket_threshold(redundancy: u32, slots: u32, attempts: u32, validators: u32) -> TicketId {
+    let num = redundancy as u64 * slots as u64;
+    let den = attempts as u64 * validators as u64;
+    if den == 0 {
+        return 0;
+    }
+    (TicketId::MAX / den as TicketId).saturating_mul(num as TicketId)
+}
+
+/// Claim the ticket for the given attempt.
+///
+/// Returns `None` if the ticket identifier is not below `threshold`.
+pub fn claim<S: RingSuite>(
+    secret: &Secret<S>,
+    prover: &RingProver<S>,
+    randomness: &[u8],
+    attempt: u32,
+    ad: impl AsRef<[u8]>,
+    threshold: TicketId,
+) -> Option<Ticket<S>>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+{
+    let input = ticket_input::<S>(randomness, attempt)?;
+    let output = secret.output(input);
+    if ticket_id(&output) >= threshold {
+        return None;
+    }
+    let proof = Prover::prove(secret, input, output, ad, prover);
+    Some(Ticket {
+        attempt,
+        output,
+        proof,
+    })
+}
+
+/// Verify a ticket.
+///
+/// Checks the attempt index bound, the threshold and the ring proof.
+/// On success, returns the ticket identifier.
+pub fn verify<S: RingSuite>(
+    ticket: &Ticket<S>,
+    verifier: &RingVerifier<S>,
+    randomness: &[u8],
+    attempts: u32,
+    ad: impl AsRef<[u8]>,
+    threshold: TicketId,
+) -> Result<TicketId, Error>
+where
+    BaseField<S>: ark_ff::PrimeField,
+    CurveConfig<S>: SWCurveConfig,
+    AffinePoint<S>: SWMapping<CurveConfig<S>>,
+{
+    if ticket.attempt >= attempts {
+        return Err(Error::InvalidData);
+    }
+    let id = ticket.id();
+    if id >= threshold {
+        return Err(Error::VerificationFailure);
+    }
+    let input = ticket_input::<S>(randomness, ticket.attempt).ok_or(Error::InvalidData)?;
+    Public::verify(input, ticket.output, ad, &ticket.proof, verifier)?;
+    Ok(id)
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::ring::testing::TEST_RING_SIZE;
+    use crate::testing as common;
+
+    #[allow(unused)]
+    pub fn claim_verify<S: RingSuite>()
+    where
+        BaseField<S>: ark_ff::PrimeField,
+        CurveConfig<S>: SWCurveConfig + Clone,
+        AffinePoint<S>: SWMapping<CurveConfig<S>>,
+    {
+        let rng = &mut ark_std::test_rng();
+        let ring_ctx = RingContext::<S>::from_rand(TEST_RING_SIZE, rng);
+
+        let secret = Secret::<S>::from_seed(common::TEST_SEED);
+        let prover_idx = 3;
+        let mut pks = common::random_vec::<AffinePoint<S>>(TEST_RING_SIZE, Some(rng));
+        pks[prover_idx] = secret.public().0;
+
+        let prover = ring_ctx.prover(&ring_ctx.prover_key(&pks), prover_idx);
+        let verifier = ring_ctx.verifier(ring_ctx.verifier_key(&pks));
+
+        let randomness = b"epoch randomness";
+        let attempts = 2;
+
+        // Everything below the max threshold.
+        let threshold = TicketId::MAX;
+        let ticket = claim(&secret, &prover, randomness, 1, b"foo", threshold).unwrap();
+        let id = verify(&ticket, &verifier, randomness, attempts, b"foo", threshold).unwrap();
+        assert_eq!(id, ticket.id());
+
+        // Wrong randomness, attempts bound and threshold.
+        assert!(verify(&ticket, &verifier, b"other", attempts, b"foo", threshold).is_err());
+        assert!(verify(&ticket, &verifier, randomness, 1, b"foo", threshold).is_err());
+        assert!(verify(&ticket, &verifier, randomness, attempts, b"foo", id).is_err());
+
+        // Nothing below the min threshold.
+        assert!(claim(&secret, &prover, randomness, 0, b"foo", 0).is_none());
+    }
+
+    #[test]
+    fn threshold() {
+        assert_eq!(ticket_threshold(1, 1, 1, 1), TicketId::MAX);
+        assert_eq!(ticket_threshold(1, 2, 2, 2), TicketId::MAX / 4 * 2);
+        assert_eq!(ticket_threshold(1, 1, 0, 1), 0);
+        assert_eq!(ticket_threshold(4, 1, 1, 1), TicketId::MAX);
+    }
+}
@@ -0,0 +1,93 @@
+//! VRF output seeded randomness.
+//!
+//! `OutputRng` is a ChaCha20 based generator seeded from the VRF output, for
+//! deterministic sampling driven by the VRF (e.g. shuffling or lotteries).
+//!
+//! The seed is the first 32 bytes of `Hasher(suite_string || 0x05 || beta_string || 0x00)`,
+//! with `beta_string` the output of `Output::hash`. The domain separator keeps the
+//! random stream independent from the other uses of the VRF output.
+//!
+//! ```rust,ignore
+//! let index = output.rng().next_u64() % candidates.len() as u64;
+//! ```
+
+use crate::*;
+use ark_std::rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+const DOM_SEP_START: u8 = 0x05;
+const DOM_SEP_END: u8 = 0x00;
+
+/// Random number generator seeded from a VRF output.
+#[derive(Clone)]
+pub struct OutputRng(ChaCha20Rng);
+
+impl<S: Suite> Output<S> {
+    /// Random number generator seeded from the output hash.
+    ///
+    /// `Suite::Hasher` output is required to be at least 32 bytes long, as is
+    /// the case for all the built-in suites. This is enforced at compile time.
+    pub fn rng(&self) -> OutputRng {
+        let hash = S::Hasher::new()
+            .chain_update(S::SUITE_ID)
+            .chain_update([DOM_SEP_START])
+            .chain_update(self.hash())
+            .chain_update([DOM_SEP_END])
+            .finalize();
+        OutputRng::from_seed(utils::hash_prefix_32::<S::Hasher>(&hash))
+    }
+}
+
+impl SeedableRng for OutputRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self(ChaCha20Rng::from_seed(seed))
+    }
+}
+
+impl RngCore for OutputRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ark_std::rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for OutputRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::testing::{Input, ScalarField, Secret};
+    use crate::testing::{random_val, TEST_SEED};
+
+    #[test]
+    fn output_rng_works() {
+        let secret = Secret::from_seed(TEST_SEED);
+        let output = secret.output(Input::new(b"foo").unwrap());
+        let other = secret.output(Input::new(b"bar").unwrap());
+
+        let value = output.rng().next_u64();
+        assert_eq!(output.rng().next_u64(), value);
+        assert_ne!(other.rng().next_u64(), value);
+
+        // Seed is domain separated from the output hash.
+        let seed = utils::hash_prefix_32(&output.hash());
+        assert_ne!(OutputRng::from_seed(seed).next_u64(), value);
+
+        // Usable where a generic rng is expected.
+        let sampled: ScalarField = random_val(Some(&mut output.rng()));
+        assert_eq!(sampled, random_val(Some(&mut output.rng())));
+    }
+}
@@ -0,0 +1,158 @@
+//! VRF signer abstraction.
+//!
+//! Decouples the proving procedures from the secret key storage, so that the secret
+//! scalar can be kept by an external device (e.g. an HSM or a remote signer).
+//!
+//! The Pedersen and Ring VRF provers are implemented for any `VrfSigner`, only the
+//! operations involving the secret scalar are delegated to the signer.
+//!
+//! ```rust,ignore
+//! struct HsmSigner { /* device handle */ }
+//!
+//! impl VrfSigner<Suite> for HsmSigner {
+//!     // Forward the operations to the device.
+//! }
+//!
+//! let proof = ring::Prover::prove(&hsm_signer, input, output, ad, &ring_prover);
+//! ```
+
+use crate::*;
+use pedersen::{PedersenParams, PedersenSuite};
+
+/// VRF signer.
+///
+/// Exposes the operations requiring the secret key.
+pub trait VrfSigner<S: PedersenSuite> {
+    /// Public key.
+    fn public(&self) -> Public<S>;
+
+    /// VRF output for the given input.
+    fn output(&self, input: Input<S>) -> Output<S>;
+
+    /// IETF VRF proof for the given input/output and additional data.
+    fn prove_ietf(&self, input: Input<S>, output: Output<S>, ad: &[u8]) -> ietf::Proof<S>;
+
+    /// Pedersen blinding factor for the given input and additional data.
+    ///
+    /// If `aux` is provided, the deterministic blinding factor is hedged with
+    /// the auxiliary randomness (see `pedersen::Prover::prove_hedged`).
+    fn blinding(&self, input: Input<S>, ad: &[u8], aux: Option<&[u8]>) -> ScalarField<S>;
+
+    /// Pedersen VRF proof for the given input/output and additional data, using
+    /// the given blinding factor.
+    ///
+    /// If `aux` is provided, the deterministic nonces are hedged with the
+    /// auxiliary randomness.
+    fn prove_pedersen(
+        &self,
+        params: &PedersenParams<S>,
+        input: Input<S>,
+        output: Output<S>,
+        ad: &[u8],
+        blinding: ScalarField<S>,
+        aux: Option<&[u8]>,
+    ) -> pedersen::Proof<S>;
+}
+
+impl<S: PedersenSuite> VrfSigner<S> for Secret<S> {
+    fn public(&self) -> Public<S> {
+        self.public
+    }
+
+    fn output(&self, input: Input<S>) -> Output<S> {
+        Secret::output(self, input)
+    }
+
+    fn prove_ietf(&self, input: Input<S>, output: Output<S>, ad: &[u8]) -> ietf::Proof<S> {
+        ietf::Prover::prove(self, input, output, ad)
+    }
+
+    fn blinding(&self, input: Input<S>, ad: &[u8], aux: Option<&[u8]>) -> ScalarField<S> {
+        pedersen::blinding(self, input, ad, aux)
+    }
+
+    fn prove_pedersen(
+        &self,
+        params: &PedersenParams<S>,
+        input: Input<S>,
+        output: Output<S>,
+        ad: &[u8],
+        blinding: ScalarField<S>,
+        aux: Option<&[u8]>,
+    ) -> pedersen::Proof<S> {
+        pedersen::prove_with_blinding(params, self, input, output, ad, blinding, aux)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suites::testing::{Input, Secret, TestSuite};
+    use crate::testing::TEST_SEED;
+    use pedersen::Prover as PedersenProver;
+
+    type Public = crate::Public<TestSuite>;
+
+    // Signer holding the secret out of reach of the provers.
+    struct RemoteSigner(Secret);
+
+    impl VrfSigner<TestSuite> for RemoteSigner {
+        fn public(&self) -> Public {
+            self.0.public()
+        }
+
+        fn output(&self, input: Input) -> Output<TestSuite> {
+            self.0.output(input)
+        }
+
+        fn prove_ietf(
+            &self,
+            input: Input,
+            output: Output<TestSuite>,
+            ad: &[u8],
+        ) -> ietf::Proof<TestSuite> {
+            self.0.prove_ietf(input, output, ad)
+        }
+
+        fn blinding(&self, input: Input, ad: &[u8], aux: Option<&[u8]>) -> ScalarField<TestSuite> {
+            VrfSigner::blinding(&self.0, input, ad, aux)
+        }
+
+        fn prove_pedersen(
+            &self,
+            params: &PedersenParams<TestSuite>,
+            input: Input,
+            output: Output<TestSuite>,
+            ad: &[u8],
+            blinding: ScalarField<TestSuite>,
+            aux: Option<&[u8]>,
+        ) -> pedersen::Proof<TestSuite> {
+            self.0.prove_pedersen(params, input, output, ad, blinding, aux)
+        }
+    }
+
+    #[test]
+    fn signer_prove_verify() {
+        use ark_std::rand::SeedableRng;
+        use pedersen::Verifier as _;
+
+        let signer = RemoteSigner(Secret::from_seed(TEST_SEED));
+        let public = VrfSigner::public(&signer);
+        let input = Input::new(b"foo").unwrap();
+        let output = VrfSigner::output(&signer, input);
+
+        let proof = signer.prove_ietf(input, output, b"bar");
+        assert!(ietf::Verifier::verify(&public, input, output, b"bar", &proof).is_ok());
+
+        let (proof, blinding) = PedersenProver::prove(&signer, input, output, b"bar");
+        let (expected, expected_blinding) =
+            PedersenProver::prove(&signer.0, input, output, b"bar");
+        assert_eq!(proof.to_bytes(), expected.to_bytes());
+        assert_eq!(blinding, expected_blinding);
+        assert!(Public::verify(input, output, b"bar", &proof).is_ok());
+
+        let mut rng = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+        let (proof, _) = signer.prove_hedged(input, output, b"bar", &mut rng);
+        assert!(Public::verify(input, output, b"bar", &proof).is_ok());
+    }
+}
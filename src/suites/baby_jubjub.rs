@@ -0,0 +1,436 @@
+//! `ECVRF Baby Jubjub Keccak-256 TAI` suite.
+//!
+//! Configuration:
+//!
+//! * `suite_string` = b"BabyJubjub_Keccak-256_TAI".
+//!
+//! - The EC group <G> is the prime subgroup of the Baby Jubjub elliptic curve
+//!   ([EIP-2494](https://eips.ethereum.org/EIPS/eip-2494)), in Twisted Edwards
+//!   form, with finite field and curve parameters as used by the Arkworks
+//!   `ark-ed-on-bn254` crate (i.e. with `a = 1`). The curve base field is the
+//!   BN254 scalar field. For this group, `fLen` = `qLen` = $32$ and
+//!   `cofactor` = $8$.
+//!
+//! - The prime subgroup generator G in <G> is the Arkworks one:
+//!   - G.x = 0x2b8cfd91b905cae31d41e7dedf4a927ee3bc429aad7e344d59d2810d82876c32
+//!   - G.y = 0x2aaa6c24a758209e90aced1f10277b762a7c1115dbc0e16ac276fc2c671a861f
+//!
+//! * `cLen` = 16.
+//!
+//! * The key pair generation primitive is `PK = sk * G`, with x the secret
+//!   key scalar and `G` the group generator. In this ciphersuite, the secret
+//!   scalar x is equal to the secret key scalar sk.
+//!
+//! * encode_to_curve_salt = PS_string (point_to_string(PK)).
+//!
+//! * The ECVRF_nonce_generation function is as specified in Section 5.4.2.1
+//!   of RFC-9381.
+//!
+//! * The int_to_string function encodes into the 32 bytes little endian
+//!   representation.
+//!
+//! * The string_to_int function decodes from the 32 bytes little endian
+//!   representation.
+//!
+//! * The point_to_string function converts a point in <G> to an octet
+//!   string using compressed form. The y coordinate is encoded using
+//!   int_to_string function and the most significant bit of the last
+//!   octet is used to keep track of the x's sign. This implies that
+//!   the point is encoded on 32 bytes.
+//!
+//! * The string_to_point function tries to decompress the point encoded
+//!   according to `point_to_string` procedure. This function MUST outputs
+//!   "INVALID" if the octet string does not decode to a point on G.
+//!
+//! * The hash function Hash is Keccak-256 (the original Keccak submission
+//!   padding, as used by Ethereum), with hLen = 32.
+//!
+//! * The `ECVRF_encode_to_curve` function uses the *try and increment* method
+//!   described in section 5.4.1.1 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381).
+//!
+//! Circuits written with circom use the `a = 168700` form of the curve, points
+//! are mapped to that form by scaling the `x` coordinate by `1/sqrt(168700)`.
+//!
+//! As the Baby Jubjub base field is the BN254 scalar field, the suite supports
+//! the ring VRF using the BN254 KZG backend.
+//!
+//! Arkworks doesn't provide the Short Weierstrass form of the curve (required by
+//! the ring proof backend), thus the suite uses the local `BabyJubjubConfig`,
+//! which extends the `ark-ed-on-bn254` curve configuration.
+
+use crate::{arkworks::te_sw_map::*, *};
+use ark_ec::{
+    models::CurveConfig,
+    short_weierstrass::{self as sw, SWCurveConfig},
+    twisted_edwards::{self as te, MontCurveConfig, TECurveConfig},
+};
+use ark_ed_on_bn254::{EdwardsConfig, Fq, Fr};
+use ark_ff::MontFp;
+
+/// Baby Jubjub curve configuration.
+///
+/// Twisted Edwards parameters are the ones of `ark-ed-on-bn254`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct BabyJubjubConfig;
+
+/// Baby Jubjub point in Twisted Edwards form.
+pub type EdwardsAffine = te::Affine<BabyJubjubConfig>;
+
+/// Baby Jubjub point in Short Weierstrass form.
+pub type SWAffine = sw::Affine<BabyJubjubConfig>;
+
+impl CurveConfig for BabyJubjubConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = <EdwardsConfig as CurveConfig>::COFACTOR;
+    const COFACTOR_INV: Fr = <EdwardsConfig as CurveConfig>::COFACTOR_INV;
+}
+
+impl TECurveConfig for BabyJubjubConfig {
+    const COEFF_A: Fq = <EdwardsConfig as TECurveConfig>::COEFF_A;
+    const COEFF_D: Fq = <EdwardsConfig as TECurveConfig>::COEFF_D;
+
+    const GENERATOR: EdwardsAffine = {
+        const G: te::Affine<EdwardsConfig> = <EdwardsConfig as TECurveConfig>::GENERATOR;
+        EdwardsAffine::new_unchecked(G.x, G.y)
+    };
+
+    type MontCurveConfig = Self;
+}
+
+impl MontCurveConfig for BabyJubjubConfig {
+    const COEFF_A: Fq = MontFp!("168698");
+    const COEFF_B: Fq = MontFp!("168700");
+
+    type TECurveConfig = Self;
+}
+
+// sage: q = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+// sage: Fq = GF(q)
+// sage: MONT_A = 168698
+// sage: MONT_B = 168700
+// sage: COEFF_A = (3 - MONT_A^2) / (3 * MONT_B^2)
+// sage: COEFF_B = (2 * MONT_A^3 - 9 * MONT_A) / (27 * MONT_B^3)
+impl SWCurveConfig for BabyJubjubConfig {
+    const COEFF_A: Fq =
+        MontFp!("3915561033734670630843635270522714716872400990323396055797168613637673095919");
+    const COEFF_B: Fq =
+        MontFp!("4217185138631398382466346491768379401896178114478749112717062407767665636606");
+
+    /// Twisted Edwards generator mapped using `utils::map_te_to_sw`.
+    const GENERATOR: SWAffine = {
+        const X: Fq =
+            MontFp!("4513000517330448244903653178865560289910339884906555605055646870021619219232");
+        const Y: Fq = MontFp!(
+            "12354950672345577792670528317750261467336531611841695810091486319550864339243"
+        );
+        SWAffine::new_unchecked(X, Y)
+    };
+}
+
+// sage: MONT_A/Fq(3) = 7296080957279758407415468581752425029516121466805344781232734728858602888105
+// sage: Fq(1)/MONT_B = 8517471723815251336780141066739207692578153395341502113578873801044114406116
+impl MapConfig for BabyJubjubConfig {
+    const MONT_A_OVER_THREE: Fq =
+        MontFp!("7296080957279758407415468581752425029516121466805344781232734728858602888105");
+    const MONT_B_INV: Fq =
+        MontFp!("8517471723815251336780141066739207692578153395341502113578873801044114406116");
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BabyJubjubKeccak256Tai;
+
+suite_types!(BabyJubjubKeccak256Tai);
+
+impl Suite for BabyJubjubKeccak256Tai {
+    const SUITE_ID: &'static [u8] = b"BabyJubjub_Keccak-256_TAI";
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = EdwardsAffine;
+    type Hasher = sha3::Keccak256;
+    type Codec = codec::ArkworksCodec;
+
+    fn nonce(sk: &ScalarField, pt: Input) -> ScalarField {
+        utils::nonce_rfc_6979::<Self>(sk, &pt.0)
+    }
+
+    /// Output point hashed without cofactor clearing (as by the test vectors).
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for BabyJubjubKeccak256Tai {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField =
+            MontFp!("5135977178158109254918671214289719802560208378607617319813587710742524849239");
+        const Y: BaseField =
+            MontFp!("8070568763098658275296271305610365776902453920483342332174354311221527795732");
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(feature = "ring")]
+mod ring_defs {
+    use super::*;
+    use crate::ring as ring_suite;
+
+    pub type PcsParams = ring_suite::PcsParams<BabyJubjubKeccak256Tai>;
+    pub type RingContext = ring_suite::RingContext<BabyJubjubKeccak256Tai>;
+    pub type RingCommitment = ring_suite::RingCommitment<BabyJubjubKeccak256Tai>;
+    pub type ProverKey = ring_suite::ProverKey<BabyJubjubKeccak256Tai>;
+    pub type VerifierKey = ring_suite::VerifierKey<BabyJubjubKeccak256Tai>;
+    pub type RingProver = ring_suite::RingProver<BabyJubjubKeccak256Tai>;
+    pub type RingVerifier = ring_suite::RingVerifier<BabyJubjubKeccak256Tai>;
+    pub type Proof = ring_suite::Proof<BabyJubjubKeccak256Tai>;
+    pub type RingSignature = ring_suite::Proof<BabyJubjubKeccak256Tai>;
+
+    /// Ring root (compressed ring commitment) size.
+    pub const RING_ROOT_SIZE: usize = 96;
+
+    /// Constant size ring root.
+    pub type RingRoot = [u8; RING_ROOT_SIZE];
+
+    impl ring_suite::RingSuite for BabyJubjubKeccak256Tai {
+        type Pairing = ark_bn254::Bn254;
+
+        /// A point on the curve not belonging to the prime order subgroup.
+        ///
+        /// Found mapping the output of `ring_proof::find_complement_point::<BabyJubjubConfig>()`
+        /// (Short Weierstrass form) using the `utils::map_sw_to_te`.
+        const ACCUMULATOR_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "12360974648980171218027620639412293654025392720865474765357504827784346165526"
+            );
+            const Y: BaseField = MontFp!(
+                "21203262999653643426297788520157772073732315680991985809818023872395048906927"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+}
+#[cfg(feature = "ring")]
+pub use ring_defs::*;
+
+/// Baby Jubjub suite using Poseidon for nonce, challenge and output hash.
+///
+/// Poseidon is defined over the curve base field (i.e. the BN254 scalar field),
+/// which is the native field of BN254 based SNARKs (e.g. circom/snarkjs).
+/// Hashing to curve is performed as for `BabyJubjubKeccak256Tai`, using Keccak-256.
+///
+/// Poseidon parameters are the ones of the Bandersnatch Poseidon suite, with
+/// round constants and MDS matrix generated for the BN254 scalar field.
+#[cfg(feature = "poseidon")]
+pub mod poseidon {
+    use super::*;
+    use crate::suites::bandersnatch::poseidon::{
+        ALPHA, CAPACITY, FULL_ROUNDS, PARTIAL_ROUNDS, RATE,
+    };
+    use ark_crypto_primitives::sponge::{
+        poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+        CryptographicSponge,
+    };
+    const NONCE_DOM: u8 = 0x01;
+    const CHALLENGE_DOM: u8 = 0x02;
+    const POINT_TO_HASH_DOM: u8 = 0x03;
+
+    /// Poseidon parameters used by the suite.
+    ///
+    /// Round constants and MDS matrix are generated via the Grain LFSR procedure
+    /// described by the Poseidon paper (`find_poseidon_ark_and_mds`).
+    pub fn poseidon_config() -> PoseidonConfig<BaseField> {
+        let (ark, mds) = find_poseidon_ark_and_mds::<BaseField>(
+            BaseField::MODULUS_BIT_SIZE as u64,
+            RATE,
+            FULL_ROUNDS as u64,
+            PARTIAL_ROUNDS as u64,
+            0,
+        );
+        PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, CAPACITY)
+    }
+
+    // Sponge initialized with the suite parameters (cached with `std`).
+    fn base_sponge() -> PoseidonSponge<BaseField> {
+        #[cfg(feature = "std")]
+        {
+            static CONFIG: std::sync::OnceLock<PoseidonConfig<BaseField>> =
+                std::sync::OnceLock::new();
+            PoseidonSponge::new(CONFIG.get_or_init(poseidon_config))
+        }
+        #[cfg(not(feature = "std"))]
+        PoseidonSponge::new(&poseidon_config())
+    }
+
+    // Sponge bound to the suite identifier, the given domain separator and the
+    // (non empty) suite context.
+    fn sponge(dom: u8) -> PoseidonSponge<BaseField> {
+        let mut sponge = base_sponge();
+        sponge.absorb(&[BabyJubjubPoseidon::SUITE_ID, &[dom]].concat());
+        let context = BabyJubjubPoseidon::CONTEXT;
+        if !context.is_empty() {
+            let len = utils::LenPrefix::<BabyJubjubPoseidon>::CONTEXT;
+            sponge.absorb(&[&[len], context].concat());
+        }
+        sponge
+    }
+
+    // Squeeze a scalar with negligible bias, reducing 16 bytes more than the
+    // scalar length modulo the scalar field order.
+    fn squeeze_scalar(sponge: &mut PoseidonSponge<BaseField>) -> ScalarField {
+        let len = (ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(8) + 16;
+        ScalarField::from_le_bytes_mod_order(&sponge.squeeze_bytes(len))
+    }
+
+    fn absorb_point(sponge: &mut PoseidonSponge<BaseField>, pt: &AffinePoint) {
+        sponge.absorb(&pt.x);
+        sponge.absorb(&pt.y);
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct BabyJubjubPoseidon;
+
+    suite_types!(BabyJubjubPoseidon);
+
+    impl Suite for BabyJubjubPoseidon {
+        const SUITE_ID: &'static [u8] = b"BabyJubjub_POSEIDON_TAI";
+        const CHALLENGE_LEN: usize = 32;
+
+        type Affine = EdwardsAffine;
+        type Hasher = sha3::Keccak256;
+        type Codec = codec::ArkworksCodec;
+
+        /// Nonce as `Poseidon(suite_id || 0x01, sk, I)`, reduced modulo the scalar
+        /// field order.
+        fn nonce(sk: &ScalarField, input: Input) -> ScalarField {
+            let mut sponge = sponge(NONCE_DOM);
+            sponge.absorb(sk);
+            absorb_point(&mut sponge, &input.0);
+            squeeze_scalar(&mut sponge)
+        }
+
+        /// Challenge as `Poseidon(suite_id || 0x02, P1, .., Pn, len(ad), ad)`, reduced
+        /// modulo the scalar field order.
+        fn challenge(pts: &[&AffinePoint], ad: &[u8]) -> ScalarField {
+            let mut sponge = sponge(CHALLENGE_DOM);
+            pts.iter().for_each(|pt| absorb_point(&mut sponge, pt));
+            sponge.absorb(&(ad.len() as u64));
+            sponge.absorb(&ad.to_vec());
+            squeeze_scalar(&mut sponge)
+        }
+
+        /// Output hash as `Poseidon(suite_id || 0x03, P)`, squeezed to 32 bytes.
+        fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+            let mut sponge = sponge(POINT_TO_HASH_DOM);
+            absorb_point(&mut sponge, pt);
+            let bytes = sponge.squeeze_bytes(<Self::Hasher as Digest>::output_size());
+            HashOutput::<Self>::clone_from_slice(&bytes)
+        }
+    }
+
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for BabyJubjubPoseidon {
+        const BLINDING_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "9379133508267362742962536723841893439406510514802663325537012723222095129257"
+            );
+            const Y: BaseField = MontFp!(
+                "3366657934363576458147171823832279128479269204789614377004884644403879044518"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+
+    #[cfg(test)]
+    suite_tests!(BabyJubjubPoseidon);
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn blinding_base_derivation() {
+        pedersen::testing::blinding_base_derivation::<BabyJubjubPoseidon>();
+    }
+}
+
+#[cfg(test)]
+suite_tests!(BabyJubjubKeccak256Tai);
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<BabyJubjubKeccak256Tai>();
+}
+
+#[cfg(all(test, feature = "ring"))]
+ring_suite_tests!(BabyJubjubKeccak256Tai);
+
+#[cfg(all(test, feature = "ring"))]
+#[test]
+fn ring_root() {
+    crate::ring::testing::ring_root::<BabyJubjubKeccak256Tai, RING_ROOT_SIZE>()
+}
+
+#[test]
+fn sw_to_te_roundtrip() {
+    let org_point = testing::random_val::<SWAffine>(None);
+
+    let te_point = map_sw_to_te::<BabyJubjubConfig>(&org_point).unwrap();
+    assert!(te_point.is_on_curve());
+
+    let sw_point = map_te_to_sw::<BabyJubjubConfig>(&te_point).unwrap();
+    assert!(sw_point.is_on_curve());
+
+    assert_eq!(org_point, sw_point);
+}
+
+#[test]
+fn sw_generator() {
+    let te_point = map_sw_to_te::<BabyJubjubConfig>(&SWAffine::generator()).unwrap();
+    assert_eq!(te_point, EdwardsAffine::generator());
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<BabyJubjubKeccak256Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/baby_jubjub_keccak256_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "BabyJubjub_Keccak-256_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<BabyJubjubKeccak256Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/baby_jubjub_keccak256_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "BabyJubjub_Keccak-256_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
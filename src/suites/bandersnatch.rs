@@ -4,6 +4,8 @@
 //!
 //! * `suite_string` = b"Bandersnatch_SHA-512_ELL2" for Twisted Edwards form.
 //! * `suite_string` = b"Bandersnatch_SW_SHA-512_TAI" for Short Weierstrass form.
+//! * `suite_string` = b"Bandersnatch_POSEIDON_TAI" for the SNARK friendly variant
+//!   (see the `poseidon` module).
 //!
 //! - The EC group <G> is the prime subgroup of the Bandersnatch elliptic curve,
 //!   in Twisted Edwards form, with finite field and curve parameters as specified in
@@ -49,10 +51,23 @@
 //!   described in section 5.4.1.2 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381),
 //!   with `h2c_suite_ID_string` = `"Bandersnatch_XMD:SHA-512_ELL2_RO_"`
 //!   and domain separation tag `DST = "ECVRF_" || h2c_suite_ID_string || suite_string`.
+//!
+//! The Short Weierstrass suite shares the above configuration, except for:
+//!
+//! * The EC group <G> is the same prime subgroup with points represented in
+//!   Short Weierstrass form (mapped from/to Twisted Edwards via the rational maps
+//!   in `utils::te_sw_map`).
+//!
+//! * The point_to_string and string_to_point functions use Arkworks compressed
+//!   encoding of Short Weierstrass points.
+//!
+//! * The `ECVRF_encode_to_curve` function uses the *try and increment* method
+//!   described in section 5.4.1.1 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381).
 
-use crate::{arkworks::te_sw_map::*, pedersen::PedersenSuite, *};
+use crate::{arkworks::te_sw_map::*, *};
 use ark_ff::MontFp;
 
+/// Short Weierstrass form suite, with *try and increment* encode to curve.
 pub mod weierstrass {
     use super::*;
 
@@ -68,9 +83,16 @@ pub mod weierstrass {
         type Affine = ark_ed_on_bls12_381_bandersnatch::SWAffine;
         type Hasher = sha2::Sha512;
         type Codec = codec::ArkworksCodec;
+
+        /// Output point hashed without cofactor clearing (as by the test vectors).
+        fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+            utils::point_to_hash_rfc_9381::<Self>(pt)
+        }
     }
 
-    impl PedersenSuite for BandersnatchSha512Tai {
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for BandersnatchSha512Tai {
+        /// Legacy constant.
         const BLINDING_BASE: AffinePoint = {
             const X: BaseField = MontFp!(
                 "4956610287995045830459834427365747411162584416641336688940534788579455781570"
@@ -90,10 +112,18 @@ pub mod weierstrass {
         pub type PcsParams = ring_suite::PcsParams<BandersnatchSha512Tai>;
         pub type RingContext = ring_suite::RingContext<BandersnatchSha512Tai>;
         pub type RingCommitment = ring_suite::RingCommitment<BandersnatchSha512Tai>;
+        pub type ProverKey = ring_suite::ProverKey<BandersnatchSha512Tai>;
         pub type VerifierKey = ring_suite::VerifierKey<BandersnatchSha512Tai>;
         pub type RingProver = ring_suite::RingProver<BandersnatchSha512Tai>;
         pub type RingVerifier = ring_suite::RingVerifier<BandersnatchSha512Tai>;
         pub type Proof = ring_suite::Proof<BandersnatchSha512Tai>;
+        pub type RingSignature = ring_suite::Proof<BandersnatchSha512Tai>;
+
+        /// Ring root (compressed ring commitment) size.
+        pub const RING_ROOT_SIZE: usize = 144;
+
+        /// Constant size ring root.
+        pub type RingRoot = [u8; RING_ROOT_SIZE];
 
         impl ring_suite::RingSuite for BandersnatchSha512Tai {
             type Pairing = ark_bls12_381::Bls12_381;
@@ -116,10 +146,17 @@ pub mod weierstrass {
     #[cfg(all(test, feature = "ring"))]
     ring_suite_tests!(BandersnatchSha512Tai);
 
+    #[cfg(all(test, feature = "ring"))]
+    #[test]
+    fn ring_root() {
+        crate::ring::testing::ring_root::<BandersnatchSha512Tai, RING_ROOT_SIZE>()
+    }
+
     #[cfg(test)]
     suite_tests!(BandersnatchSha512Tai);
 }
 
+/// Twisted Edwards form suite, with *Elligator2* encode to curve.
 pub mod edwards {
     use super::*;
 
@@ -143,9 +180,15 @@ pub mod edwards {
             let h2c_suite_id = b"Bandersnatch_XMD:SHA-512_ELL2_RO_";
             utils::hash_to_curve_ell2_rfc_9380::<Self>(data, h2c_suite_id)
         }
+
+        /// Output point hashed without cofactor clearing (as by the test vectors).
+        fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+            utils::point_to_hash_rfc_9381::<Self>(pt)
+        }
     }
 
-    impl PedersenSuite for BandersnatchSha512Ell2 {
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for BandersnatchSha512Ell2 {
         /// Found mapping `BLINDING_BASE` of `weierstrass` module using the `utils::map_sw_to_te`
         const BLINDING_BASE: AffinePoint = {
             const X: BaseField = MontFp!(
@@ -182,10 +225,18 @@ pub mod edwards {
         pub type PcsParams = ring_suite::PcsParams<BandersnatchSha512Ell2>;
         pub type RingContext = ring_suite::RingContext<BandersnatchSha512Ell2>;
         pub type RingCommitment = ring_suite::RingCommitment<BandersnatchSha512Ell2>;
+        pub type ProverKey = ring_suite::ProverKey<BandersnatchSha512Ell2>;
         pub type VerifierKey = ring_suite::VerifierKey<BandersnatchSha512Ell2>;
         pub type RingProver = ring_suite::RingProver<BandersnatchSha512Ell2>;
         pub type RingVerifier = ring_suite::RingVerifier<BandersnatchSha512Ell2>;
         pub type Proof = ring_suite::Proof<BandersnatchSha512Ell2>;
+        pub type RingSignature = ring_suite::Proof<BandersnatchSha512Ell2>;
+
+        /// Ring root (compressed ring commitment) size.
+        pub const RING_ROOT_SIZE: usize = 144;
+
+        /// Constant size ring root.
+        pub type RingRoot = [u8; RING_ROOT_SIZE];
 
         impl ring_suite::RingSuite for BandersnatchSha512Ell2 {
             type Pairing = ark_bls12_381::Bls12_381;
@@ -210,6 +261,12 @@ pub mod edwards {
     #[cfg(all(test, feature = "ring"))]
     ring_suite_tests!(BandersnatchSha512Ell2);
 
+    #[cfg(all(test, feature = "ring"))]
+    #[test]
+    fn ring_root() {
+        crate::ring::testing::ring_root::<BandersnatchSha512Ell2, RING_ROOT_SIZE>()
+    }
+
     #[cfg(test)]
     suite_tests!(BandersnatchSha512Ell2);
 
@@ -221,6 +278,172 @@ pub mod edwards {
     }
 }
 
+/// Twisted Edwards form suite, with Poseidon based nonce, challenge and output hash.
+///
+/// SNARK friendly variant of the `edwards` suite. Nonce, challenge and output hash
+/// are computed using a Poseidon sponge over the BLS12-381 scalar field (i.e. the
+/// Bandersnatch base field), thus proofs can be cheaply verified within circuits
+/// defined over that field. Points are absorbed as `(x, y)` coordinates.
+///
+/// Encode to curve (*try and increment*), secret key derivation from seed and
+/// Pedersen blinding factor still use SHA-512.
+#[cfg(feature = "poseidon")]
+pub mod poseidon {
+    use super::*;
+    use ark_crypto_primitives::sponge::{
+        poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+        CryptographicSponge,
+    };
+
+    /// Poseidon full rounds.
+    pub const FULL_ROUNDS: usize = 8;
+    /// Poseidon partial rounds.
+    pub const PARTIAL_ROUNDS: usize = 57;
+    /// Poseidon S-box exponent.
+    pub const ALPHA: u64 = 5;
+    /// Poseidon sponge rate.
+    pub const RATE: usize = 2;
+    /// Poseidon sponge capacity.
+    pub const CAPACITY: usize = 1;
+
+    const NONCE_DOM: u8 = 0x01;
+    const CHALLENGE_DOM: u8 = 0x02;
+    const POINT_TO_HASH_DOM: u8 = 0x03;
+
+    /// Poseidon parameters used by the suite.
+    ///
+    /// Round constants and MDS matrix are generated via the Grain LFSR procedure
+    /// described by the Poseidon paper (`find_poseidon_ark_and_mds`). Circuits
+    /// verifying the suite proofs are required to use the same parameters.
+    pub fn poseidon_config() -> PoseidonConfig<BaseField> {
+        let (ark, mds) = find_poseidon_ark_and_mds::<BaseField>(
+            BaseField::MODULUS_BIT_SIZE as u64,
+            RATE,
+            FULL_ROUNDS as u64,
+            PARTIAL_ROUNDS as u64,
+            0,
+        );
+        PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, CAPACITY)
+    }
+
+    // Sponge initialized with the suite parameters.
+    //
+    // Parameters generation is quite expensive, thus with `std` these are
+    // computed once and cached.
+    fn base_sponge() -> PoseidonSponge<BaseField> {
+        #[cfg(feature = "std")]
+        {
+            static CONFIG: std::sync::OnceLock<PoseidonConfig<BaseField>> =
+                std::sync::OnceLock::new();
+            PoseidonSponge::new(CONFIG.get_or_init(poseidon_config))
+        }
+        #[cfg(not(feature = "std"))]
+        PoseidonSponge::new(&poseidon_config())
+    }
+
+    // Sponge bound to the suite identifier, the given domain separator and the
+    // (non empty) suite context.
+    fn sponge(dom: u8) -> PoseidonSponge<BaseField> {
+        let mut sponge = base_sponge();
+        sponge.absorb(&[BandersnatchPoseidon::SUITE_ID, &[dom]].concat());
+        let context = BandersnatchPoseidon::CONTEXT;
+        if !context.is_empty() {
+            let len = utils::LenPrefix::<BandersnatchPoseidon>::CONTEXT;
+            sponge.absorb(&[&[len], context].concat());
+        }
+        sponge
+    }
+
+    // Squeeze a scalar with negligible bias.
+    //
+    // The scalar field is not the sponge native field, thus 16 bytes more than
+    // the scalar length are squeezed and reduced modulo the scalar field order.
+    fn squeeze_scalar(sponge: &mut PoseidonSponge<BaseField>) -> ScalarField {
+        let len = (ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(8) + 16;
+        ScalarField::from_le_bytes_mod_order(&sponge.squeeze_bytes(len))
+    }
+
+    fn absorb_point(sponge: &mut PoseidonSponge<BaseField>, pt: &AffinePoint) {
+        sponge.absorb(&pt.x);
+        sponge.absorb(&pt.y);
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct BandersnatchPoseidon;
+
+    suite_types!(BandersnatchPoseidon);
+
+    impl Suite for BandersnatchPoseidon {
+        const SUITE_ID: &'static [u8] = b"Bandersnatch_POSEIDON_TAI";
+        const CHALLENGE_LEN: usize = 32;
+
+        type Affine = ark_ed_on_bls12_381_bandersnatch::EdwardsAffine;
+        type Hasher = sha2::Sha512;
+        type Codec = codec::ArkworksCodec;
+
+        /// Nonce as `Poseidon(suite_id || 0x01, sk, I)`, reduced modulo the scalar
+        /// field order.
+        fn nonce(sk: &ScalarField, input: Input) -> ScalarField {
+            let mut sponge = sponge(NONCE_DOM);
+            sponge.absorb(sk);
+            absorb_point(&mut sponge, &input.0);
+            squeeze_scalar(&mut sponge)
+        }
+
+        /// Challenge as `Poseidon(suite_id || 0x02, P1, .., Pn, len(ad), ad)`, reduced
+        /// modulo the scalar field order.
+        fn challenge(pts: &[&AffinePoint], ad: &[u8]) -> ScalarField {
+            let mut sponge = sponge(CHALLENGE_DOM);
+            pts.iter().for_each(|pt| absorb_point(&mut sponge, pt));
+            sponge.absorb(&(ad.len() as u64));
+            sponge.absorb(&ad.to_vec());
+            squeeze_scalar(&mut sponge)
+        }
+
+        /// Output hash as `Poseidon(suite_id || 0x03, P)`, squeezed to 64 bytes.
+        fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+            let mut sponge = sponge(POINT_TO_HASH_DOM);
+            absorb_point(&mut sponge, pt);
+            let bytes = sponge.squeeze_bytes(<Self::Hasher as Digest>::output_size());
+            HashOutput::<Self>::clone_from_slice(&bytes)
+        }
+    }
+
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for BandersnatchPoseidon {
+        const BLINDING_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "19950489595056454338895978125701421105218879868426998486516746221695026296884"
+            );
+            const Y: BaseField = MontFp!(
+                "31695161351024984898371905096223760243051736512921875905266524074703533905415"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+
+    #[cfg(test)]
+    suite_tests!(BandersnatchPoseidon);
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn blinding_base_derivation() {
+        pedersen::testing::blinding_base_derivation::<BandersnatchPoseidon>();
+    }
+
+    #[test]
+    fn poseidon_challenge() {
+        let pt = BandersnatchPoseidon::generator();
+        let c1 = BandersnatchPoseidon::challenge(&[&pt], b"foo");
+        let c2 = BandersnatchPoseidon::challenge(&[&pt], b"foo\0");
+        assert_ne!(c1, c2);
+        assert_ne!(
+            c1,
+            utils::challenge_rfc_9381::<BandersnatchPoseidon>(&[&pt], b"foo")
+        );
+    }
+}
+
 // sage: q = 52435875175126190479447740508185965837690552500527637822603658699938581184513
 // sage: Fq = GF(q)
 // sage: MONT_A = 29978822694968839326280996386011761570173833766074948509196803838190355340952
@@ -253,7 +476,7 @@ mod tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ietf"))]
 mod test_vectors_ietf_ed {
     use super::edwards::*;
     use crate::testing;
@@ -277,7 +500,7 @@ mod test_vectors_ietf_ed {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "pedersen"))]
 mod test_vectors_pedersen_ed {
     use super::edwards::*;
     use crate::testing;
@@ -342,7 +565,7 @@ mod test_vectors_ring_ed {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ietf"))]
 mod test_vectors_ietf_sw {
     use super::weierstrass::*;
     use crate::testing;
@@ -366,7 +589,7 @@ mod test_vectors_ietf_sw {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "pedersen"))]
 mod test_vectors_pedersen_sw {
     use super::weierstrass::*;
     use crate::testing;
@@ -0,0 +1,157 @@
+//! `ECVRF BLS12-381 G1 SHA-512 TAI` suite.
+//!
+//! Configuration:
+//!
+//! * `suite_string` = b"BLS12-381_G1_SHA-512_TAI".
+//!
+//! - The EC group <G> is the prime subgroup G1 of the BLS12-381 pairing friendly
+//!   elliptic curve `y^2 = x^3 + 4`, with finite field and curve parameters as
+//!   specified by [RFC-9380](https://datatracker.ietf.org/doc/rfc9380) section 8.8.1.
+//!   For this group, `fLen` = $48$, `qLen` = $32$ and
+//!   `cofactor` = $0x396c8c005555e1568c00aaab0000aaab$.
+//!
+//! - The prime subgroup generator G in <G> is the standard one:
+//!   - G.x = 0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb
+//!   - G.y = 0x08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+//!
+//! * `cLen` = 32.
+//!
+//! * The key pair generation primitive is `PK = sk * G`, with x the secret
+//!   key scalar and `G` the group generator. In this ciphersuite, the secret
+//!   scalar x is equal to the secret key scalar sk.
+//!
+//! * encode_to_curve_salt = PS_string (point_to_string(PK)).
+//!
+//! * The ECVRF_nonce_generation function is as specified in Section 5.4.2.2
+//!   of RFC-9381.
+//!
+//! * The int_to_string function encodes into the 32 bytes little endian
+//!   representation.
+//!
+//! * The string_to_int function decodes from the 32 bytes little endian
+//!   representation.
+//!
+//! * The point_to_string function converts a point in <G> to an octet
+//!   string using the 48 bytes compressed form used by Zcash and by most of
+//!   the BLS12-381 implementations (which is the Arkworks one). The x coordinate
+//!   is encoded as big endian, and the three most significant bits of the first
+//!   octet are used as compression, point at infinity and y's sign flags.
+//!
+//! * The string_to_point function tries to decompress the point encoded
+//!   according to `point_to_string` procedure. This function MUST outputs
+//!   "INVALID" if the octet string does not decode to a point on the curve.
+//!
+//! * The hash function Hash is SHA-512 as specified in
+//!   [RFC6234](https://www.rfc-editor.org/rfc/rfc6234), with hLen = 64.
+//!
+//! * The `ECVRF_encode_to_curve` function uses the *try and increment* method
+//!   described in section 5.4.1.1 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381).
+//!   The cofactor is cleared using the effective cofactor `h_eff = 0xd201000000010001`
+//!   (RFC-9380 section 8.8.1), which maps the point to G1.
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Bls12_381G1Sha512Tai;
+
+suite_types!(Bls12_381G1Sha512Tai);
+
+impl Suite for Bls12_381G1Sha512Tai {
+    const SUITE_ID: &'static [u8] = b"BLS12-381_G1_SHA-512_TAI";
+    const CHALLENGE_LEN: usize = 32;
+
+    type Affine = ark_bls12_381::G1Affine;
+    type Hasher = sha2::Sha512;
+    type Codec = codec::ArkworksCodec;
+
+    /// Output point hashed without cofactor clearing (as by the test vectors).
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Bls12_381G1Sha512Tai {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!("66617520522630710695061811954323585300843275146894940939693252263630257904972620061017485322667295418131825120444");
+        const Y: BaseField = MontFp!("1206475269724514910789101311851444156480849899233706158707331860155929776158686687199877413507443234122085822352031");
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(test)]
+suite_tests!(Bls12_381G1Sha512Tai);
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<Bls12_381G1Sha512Tai>();
+}
+
+#[test]
+fn data_to_point_in_g1() {
+    let pt = Bls12_381G1Sha512Tai::data_to_point(b"foo").unwrap();
+    assert!(pt.is_on_curve());
+    assert!(pt.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn data_to_point_arkworks_wb_map() {
+    use ark_ec::hashing::curve_maps::wb::WBMap;
+    type Map = WBMap<ark_bls12_381::g1::Config>;
+    let h2c_suite_id = b"BLS12381G1_XMD:SHA-512_SSWU_RO_";
+    let pt =
+        utils::hash_to_curve_rfc_9380::<Bls12_381G1Sha512Tai, Map>(b"foo", h2c_suite_id).unwrap();
+    assert!(pt.is_on_curve());
+    assert!(pt.is_in_correct_subgroup_assuming_on_curve());
+    let pt2 =
+        utils::hash_to_curve_rfc_9380::<Bls12_381G1Sha512Tai, Map>(b"bar", h2c_suite_id).unwrap();
+    assert_ne!(pt, pt2);
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<Bls12_381G1Sha512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/bls12_381_g1_sha512_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "BLS12-381_G1_SHA-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<Bls12_381G1Sha512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/bls12_381_g1_sha512_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "BLS12-381_G1_SHA-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
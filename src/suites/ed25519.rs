@@ -48,7 +48,7 @@
 //!    Section 5.4.1.1, with interpret_hash_value_as_a_point(s) =
 //!    string_to_point(s[0]...s[31]).
 
-use crate::{pedersen::PedersenSuite, *};
+use crate::*;
 use ark_ff::MontFp;
 
 #[derive(Debug, Copy, Clone)]
@@ -63,9 +63,16 @@ impl Suite for Ed25519Sha512Tai {
     type Affine = ark_ed25519::EdwardsAffine;
     type Hasher = sha2::Sha512;
     type Codec = codec::ArkworksCodec;
+
+    /// Output point hashed without cofactor clearing (as by the test vectors).
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
 }
 
-impl PedersenSuite for Ed25519Sha512Tai {
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Ed25519Sha512Tai {
+    /// Legacy constant.
     const BLINDING_BASE: AffinePoint = {
         const X: BaseField =
             MontFp!("1181072390894490040170698195029164902368238760122173135634802939739986120753");
@@ -79,7 +86,7 @@ impl PedersenSuite for Ed25519Sha512Tai {
 #[cfg(test)]
 suite_tests!(Ed25519Sha512Tai);
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ietf"))]
 mod test_vectors_ietf {
     use super::*;
 
@@ -102,7 +109,7 @@ mod test_vectors_ietf {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "pedersen"))]
 mod test_vectors_pedersen {
     use super::*;
 
@@ -0,0 +1,195 @@
+//! `ECVRF-EDWARDS25519-SHA512-ELL2` suite, as specified by RFC-9381.
+//!
+//! Configuration (RFC-9381):
+//!
+//! *  suite_string = 0x04.
+//!
+//! *  The EC group G is the edwards25519 elliptic curve, with the finite
+//!    field and curve parameters as defined in Table 1 in Section 5.1 of
+//!    [RFC8032].  For this group, fLen = qLen = 32 and cofactor = 8.
+//!
+//! *  cLen = 16.
+//!
+//! *  The secret key and generation of the secret scalar and the public
+//!    key are specified in Section 5.1.5 of [RFC8032].
+//!
+//! *  encode_to_curve_salt = PK_string.
+//!
+//! *  The ECVRF_nonce_generation function is as specified in
+//!    Section 5.4.2.2.
+//!
+//! *  The int_to_string and string_to_int functions use little-endian
+//!    representation, as specified by Section 5.1.2 of [RFC8032].
+//!
+//! *  The point_to_string and string_to_point functions use the encoding
+//!    specified in Sections 5.1.2 and 5.1.3 of [RFC8032].
+//!
+//! *  The hash function Hash is SHA-512 as specified in [RFC6234], with
+//!    hLen = 64.
+//!
+//! *  The ECVRF_encode_to_curve function is as specified in
+//!    Section 5.4.1.2, with `h2c_suite_ID_string` = `"edwards25519_XMD:SHA-512_ELL2_NU_"`.
+//!
+//! As for the TAI variant (see `ed25519_rfc_9381`), secret keys are handled as
+//! scalars and the nonce is derived starting from the secret scalar encoding.
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Ed25519Sha512Ell2;
+
+suite_types!(Ed25519Sha512Ell2);
+
+impl Suite for Ed25519Sha512Ell2 {
+    const SUITE_ID: &'static [u8] = &[0x04];
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = ark_ed25519::EdwardsAffine;
+    type Hasher = sha2::Sha512;
+    type Codec = codec::Rfc8032Codec;
+
+    /// Encode to curve as specified by RFC-9381 section 5.4.1.2.
+    ///
+    /// Uses the `encode_to_curve` (non-uniform) procedure of RFC-9380.
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint> {
+        const SEC_PARAM: usize = 128;
+
+        // Domain Separation Tag := "ECVRF_" || h2c_suite_ID_string || suite_string
+        let h2c_suite_id: &[u8] = b"edwards25519_XMD:SHA-512_ELL2_NU_";
+        let dst = [&b"ECVRF_"[..], h2c_suite_id, Self::SUITE_ID].concat();
+
+        let u = utils::hash_to_field::<BaseField, utils::ExpandXmd<sha2::Sha512>>(
+            data, &dst, 1, SEC_PARAM,
+        )?;
+        let pt = map_to_curve_elligator2_edwards25519(u[0]);
+        Some(pt.clear_cofactor())
+    }
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Ed25519Sha512Ell2 {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "40222428094737453776673313852752974284345107084212695798273952985874762957660"
+        );
+        const Y: BaseField = MontFp!(
+            "44805132875584202001589501265143942599315224537767770484975797443160852437193"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+/// Elligator2 map to edwards25519 as specified by RFC-9380 section 6.8.2.
+///
+/// Maps to curve25519 (`J = 486662`, `K = 1`, `Z = 2`) and then applies the
+/// rational map to edwards25519 given in RFC-9380 appendix D.
+///
+/// The generic `arkworks::elligator2` map is not used, as it goes through the
+/// scaled Montgomery model of the Arkworks curve config, which may produce the
+/// negation of the point mandated by the RFC.
+fn map_to_curve_elligator2_edwards25519(u: BaseField) -> AffinePoint {
+    use ark_ec::hashing::curve_maps::swu::parity;
+    use ark_ff::{Field, One, Zero};
+
+    const J: BaseField = MontFp!("486662");
+    const Z: BaseField = MontFp!("2");
+    // sqrt(-486664), with sgn0(C1) == 0
+    const C1: BaseField =
+        MontFp!("6853475219497561581579357271197624642482790079785650197046958215289687604742");
+
+    let one = BaseField::one();
+    let gx = |x: BaseField| (x.square() + J * x + one) * x;
+
+    // 1. x1 = -J * inv0(1 + Z * u^2)
+    // 2. If x1 == 0, set x1 = -J
+    let mut x1 = -J
+        * (one + Z * u.square())
+            .inverse()
+            .unwrap_or(BaseField::zero());
+    if x1.is_zero() {
+        x1 = -J;
+    }
+    // 3. gx1 = x1^3 + J * x1^2 + x1
+    let gx1 = gx(x1);
+    // 4. x2 = -x1 - J
+    let x2 = -x1 - J;
+    // 5. If is_square(gx1), set s = x1, t = sqrt(gx1) with sgn0(t) == 1
+    // 6. Else set s = x2, t = sqrt(gx2) with sgn0(t) == 0
+    let (s, mut t, sgn0) = if gx1.legendre().is_qr() {
+        let t = gx1
+            .sqrt()
+            .expect("We have checked that gx1 is a quadratic residue. Q.E.D");
+        (x1, t, true)
+    } else {
+        let t = gx(x2)
+            .sqrt()
+            .expect("gx2 is a quadratic residue because gx1 is not. Q.E.D");
+        (x2, t, false)
+    };
+    if parity(&t) != sgn0 {
+        t = -t;
+    }
+
+    // Rational map: (v, w) = (C1 * s / t, (s - 1) / (s + 1))
+    let den = (s + one) * t;
+    if den.is_zero() {
+        return <AffinePoint as AffineRepr>::zero();
+    }
+    let den = den.inverse().expect("Non zero element has inverse. Q.E.D.");
+    let v = C1 * s * (s + one) * den;
+    let w = (s - one) * t * den;
+    AffinePoint::new_unchecked(v, w)
+}
+
+#[cfg(test)]
+suite_tests!(Ed25519Sha512Ell2);
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<Ed25519Sha512Ell2>();
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<Ed25519Sha512Ell2>;
+
+    // Vectors from RFC-9381
+    const TEST_VECTORS_FILE_RFC_9381: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/ed25519_sha512_ell2_ietf_vectors_rfc_9381.json"
+    );
+
+    /// Secret keys in the vectors are the RFC-8032 secret scalars.
+    ///
+    /// As the proving nonce is not derived from the RFC-8032 secret key string, the
+    /// published proofs are checked via verification instead of being reproduced.
+    #[test]
+    fn process_rfc_9381() {
+        use crate::ietf::Verifier;
+        use std::{fs::File, io::BufReader};
+
+        let file = File::open(TEST_VECTORS_FILE_RFC_9381).unwrap();
+        let vector_maps: Vec<testing::TestVectorMap> =
+            serde_json::from_reader(BufReader::new(file)).unwrap();
+        assert!(!vector_maps.is_empty());
+
+        for map in vector_maps.iter() {
+            let vector = <V as testing::TestVectorTrait>::from_map(map);
+            let base = &vector.base;
+            base.run();
+
+            let proof = ietf::Proof {
+                c: vector.c,
+                s: vector.s,
+            };
+            let input = Input::from(base.h);
+            let output = Output::from(base.gamma);
+            let public = crate::Public(base.pk);
+            assert!(public.verify(input, output, &base.ad, &proof).is_ok());
+        }
+    }
+}
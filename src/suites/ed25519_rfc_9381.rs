@@ -0,0 +1,160 @@
+//! `ECVRF-EDWARDS25519-SHA512-TAI` suite, as specified by RFC-9381.
+//!
+//! Configuration (RFC-9381):
+//!
+//! *  suite_string = 0x03.
+//!
+//! *  The EC group G is the edwards25519 elliptic curve, with the finite
+//!    field and curve parameters as defined in Table 1 in Section 5.1 of
+//!    [RFC8032].  For this group, fLen = qLen = 32 and cofactor = 8.
+//!
+//! *  cLen = 16.
+//!
+//! *  The secret key and generation of the secret scalar and the public
+//!    key are specified in Section 5.1.5 of [RFC8032].
+//!
+//! *  encode_to_curve_salt = PK_string.
+//!
+//! *  The ECVRF_nonce_generation function is as specified in
+//!    Section 5.4.2.2.
+//!
+//! *  The int_to_string function is implemented as specified in the
+//!    first paragraph of Section 5.1.2 of [RFC8032].  (This is little-
+//!    endian representation.)
+//!
+//! *  The string_to_int function interprets the string as an integer in
+//!    little-endian representation.
+//!
+//! *  The point_to_string function converts a point on E to an octet
+//!    string according to the encoding specified in Section 5.1.2 of
+//!    [RFC8032].  This implies that ptLen = fLen = 32.
+//!
+//! *  The string_to_point function converts an octet string to a point
+//!    on E according to the encoding specified in Section 5.1.3 of
+//!    [RFC8032].  This function MUST output "INVALID" if the octet
+//!    string does not decode to a point on the curve E.
+//!
+//! *  The hash function Hash is SHA-512 as specified in [RFC6234], with
+//!    hLen = 64.
+//!
+//! *  The ECVRF_encode_to_curve function is as specified in
+//!    Section 5.4.1.1, with interpret_hash_value_as_a_point(s) =
+//!    string_to_point(s[0]...s[31]).
+//!
+//! Secret keys are handled as scalars (see `Secret::from_scalar`). RFC-9381
+//! nonces are derived from the hash of the RFC-8032 secret key string, which is
+//! not available to `Suite::nonce`. Thus the nonce is derived as in Section 5.4.2.2
+//! but starting from the secret scalar encoding. Proofs are valid for any RFC-9381
+//! compliant verifier, while outputs and verification of third-party proofs are
+//! fully interoperable.
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Ed25519Sha512TaiRfc9381;
+
+suite_types!(Ed25519Sha512TaiRfc9381);
+
+impl Suite for Ed25519Sha512TaiRfc9381 {
+    const SUITE_ID: &'static [u8] = &[0x03];
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = ark_ed25519::EdwardsAffine;
+    type Hasher = sha2::Sha512;
+    type Codec = codec::Rfc8032Codec;
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Ed25519Sha512TaiRfc9381 {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "51539877271752890400025388171570284529227818362274631880307648839977719853447"
+        );
+        const Y: BaseField = MontFp!(
+            "11536514690401684901995948516370914849665509818008806261704612231175845257459"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(test)]
+suite_tests!(Ed25519Sha512TaiRfc9381);
+
+#[test]
+fn codec_rejects_wrong_length() {
+    let buf = codec::point_encode::<Ed25519Sha512TaiRfc9381>(&Ed25519Sha512TaiRfc9381::generator());
+    assert!(codec::point_decode::<Ed25519Sha512TaiRfc9381>(&buf).is_ok());
+    let mut extra = buf.clone();
+    extra.push(0);
+    assert!(codec::point_decode::<Ed25519Sha512TaiRfc9381>(&extra).is_err());
+    assert!(codec::point_decode::<Ed25519Sha512TaiRfc9381>(&buf[..31]).is_err());
+}
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<Ed25519Sha512TaiRfc9381>();
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<Ed25519Sha512TaiRfc9381>;
+
+    // Vectors from RFC-9381
+    const TEST_VECTORS_FILE_RFC_9381: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/ed25519_sha512_tai_ietf_vectors_rfc_9381.json"
+    );
+
+    /// Secret keys in the vectors are the RFC-8032 secret scalars.
+    ///
+    /// As the proving nonce is not derived from the RFC-8032 secret key string, the
+    /// published proofs are checked via verification instead of being reproduced.
+    #[test]
+    fn process_rfc_9381() {
+        use crate::ietf::Verifier;
+        use std::{fs::File, io::BufReader};
+
+        let file = File::open(TEST_VECTORS_FILE_RFC_9381).unwrap();
+        let vector_maps: Vec<testing::TestVectorMap> =
+            serde_json::from_reader(BufReader::new(file)).unwrap();
+        assert!(!vector_maps.is_empty());
+
+        for map in vector_maps.iter() {
+            let vector = <V as testing::TestVectorTrait>::from_map(map);
+            let base = &vector.base;
+            base.run();
+
+            let proof = ietf::Proof {
+                c: vector.c,
+                s: vector.s,
+            };
+            let input = Input::from(base.h);
+            let output = Output::from(base.gamma);
+            let public = crate::Public(base.pk);
+            assert!(public.verify(input, output, &base.ad, &proof).is_ok());
+
+            // Proof encoding: `c || s`
+            let mut buf = Vec::new();
+            proof.serialize_compressed(&mut buf).unwrap();
+            assert_eq!(
+                buf,
+                [map.get_bytes("proof_c"), map.get_bytes("proof_s")].concat()
+            );
+        }
+    }
+
+    #[test]
+    fn prove_verify_rfc_9381() {
+        use crate::ietf::{Prover, Verifier};
+
+        let secret = Secret::from_seed(b"foo");
+        let input = Input::new(b"bar").unwrap();
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, b"");
+        assert!(secret.public().verify(input, output, b"", &proof).is_ok());
+    }
+}
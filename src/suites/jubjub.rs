@@ -0,0 +1,219 @@
+//! `ECVRF Jubjub SHA-512 TAI` suite.
+//!
+//! Configuration:
+//!
+//! * `suite_string` = b"Jubjub_SHA-512_TAI".
+//!
+//! - The EC group <G> is the prime subgroup of the Jubjub elliptic curve,
+//!   in Twisted Edwards form, with finite field and curve parameters as specified
+//!   by the [Zcash protocol specification](https://zips.z.cash/protocol/protocol.pdf)
+//!   (section 5.4.9.3). The curve base field is the BLS12-381 scalar field.
+//!   For this group, `fLen` = `qLen` = $32$ and `cofactor` = $8$.
+//!
+//! - The prime subgroup generator G in <G> is the Arkworks one:
+//!   - G.x = 0x11dafe5d23e1218086a365b99fbf3d3be72f6afd7d1f72623e6b071492d1122b
+//!   - G.y = 0x1d523cf1ddab1a1793132e78c866c0c33e26ba5cc220fed7cc3f870e59d292aa
+//!
+//! * `cLen` = 32.
+//!
+//! * The key pair generation primitive is `PK = sk * G`, with x the secret
+//!   key scalar and `G` the group generator. In this ciphersuite, the secret
+//!   scalar x is equal to the secret key scalar sk.
+//!
+//! * encode_to_curve_salt = PS_string (point_to_string(PK)).
+//!
+//! * The ECVRF_nonce_generation function is as specified in Section 5.4.2.2
+//!   of RFC-9381.
+//!
+//! * The int_to_string function encodes into the 32 bytes little endian
+//!   representation.
+//!
+//! * The string_to_int function decodes from the 32 bytes little endian
+//!   representation.
+//!
+//! * The point_to_string function converts a point in <G> to an octet
+//!   string using compressed form. The y coordinate is encoded using
+//!   int_to_string function and the most significant bit of the last
+//!   octet is used to keep track of the x's sign. This implies that
+//!   the point is encoded on 32 bytes.
+//!
+//! * The string_to_point function tries to decompress the point encoded
+//!   according to `point_to_string` procedure. This function MUST outputs
+//!   "INVALID" if the octet string does not decode to a point on G.
+//!
+//! * The hash function Hash is SHA-512 as specified in
+//!   [RFC6234](https://www.rfc-editor.org/rfc/rfc6234), with hLen = 64.
+//!
+//! * The `ECVRF_encode_to_curve` function uses the *try and increment* method
+//!   described in section 5.4.1.1 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381).
+//!
+//! As the Jubjub base field is the BLS12-381 scalar field, the suite supports
+//! the ring VRF using the BLS12-381 KZG backend (as for Bandersnatch).
+
+use crate::{arkworks::te_sw_map::*, *};
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct JubjubSha512Tai;
+
+suite_types!(JubjubSha512Tai);
+
+impl Suite for JubjubSha512Tai {
+    const SUITE_ID: &'static [u8] = b"Jubjub_SHA-512_TAI";
+    const CHALLENGE_LEN: usize = 32;
+
+    type Affine = ark_ed_on_bls12_381::EdwardsAffine;
+    type Hasher = sha2::Sha512;
+    type Codec = codec::ArkworksCodec;
+
+    /// Output point hashed without cofactor clearing (as by the test vectors).
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for JubjubSha512Tai {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "23801291369399617202079454983265353398785175407954789245124583599428800260416"
+        );
+        const Y: BaseField = MontFp!(
+            "25726345450932542278249408578918210940523236672712163504931775473372337340976"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(feature = "ring")]
+mod ring_defs {
+    use super::*;
+    use crate::ring as ring_suite;
+
+    pub type PcsParams = ring_suite::PcsParams<JubjubSha512Tai>;
+    pub type RingContext = ring_suite::RingContext<JubjubSha512Tai>;
+    pub type RingCommitment = ring_suite::RingCommitment<JubjubSha512Tai>;
+    pub type ProverKey = ring_suite::ProverKey<JubjubSha512Tai>;
+    pub type VerifierKey = ring_suite::VerifierKey<JubjubSha512Tai>;
+    pub type RingProver = ring_suite::RingProver<JubjubSha512Tai>;
+    pub type RingVerifier = ring_suite::RingVerifier<JubjubSha512Tai>;
+    pub type Proof = ring_suite::Proof<JubjubSha512Tai>;
+    pub type RingSignature = ring_suite::Proof<JubjubSha512Tai>;
+
+    /// Ring root (compressed ring commitment) size.
+    pub const RING_ROOT_SIZE: usize = 144;
+
+    /// Constant size ring root.
+    pub type RingRoot = [u8; RING_ROOT_SIZE];
+
+    impl ring_suite::RingSuite for JubjubSha512Tai {
+        type Pairing = ark_bls12_381::Bls12_381;
+
+        /// A point on the curve not belonging to the prime order subgroup.
+        ///
+        /// Found mapping the output of `ring_proof::find_complement_point::<JubjubConfig>()`
+        /// (Short Weierstrass form) using the `utils::map_sw_to_te`.
+        const ACCUMULATOR_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "46709552365794945813764361491392194119818877539138900041435822065667878020079"
+            );
+            const Y: BaseField = MontFp!(
+                "44500937182881688505471953202032991349005483448458484873818129667677703188835"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+}
+#[cfg(feature = "ring")]
+pub use ring_defs::*;
+
+// sage: q = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+// sage: Fq = GF(q)
+// sage: MONT_A = 40962
+// sage: MONT_B = -40964
+// sage: MONT_A/Fq(3) = 13654
+// sage: Fq(1)/MONT_B = 21403678078392857899786292086646263420857917399700288839755780126981569572948
+impl MapConfig for ark_ed_on_bls12_381::JubjubConfig {
+    const MONT_A_OVER_THREE: ark_ed_on_bls12_381::Fq = MontFp!("13654");
+    const MONT_B_INV: ark_ed_on_bls12_381::Fq =
+        MontFp!("21403678078392857899786292086646263420857917399700288839755780126981569572948");
+}
+
+#[cfg(test)]
+suite_tests!(JubjubSha512Tai);
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<JubjubSha512Tai>();
+}
+
+#[cfg(all(test, feature = "ring"))]
+ring_suite_tests!(JubjubSha512Tai);
+
+#[cfg(all(test, feature = "ring"))]
+#[test]
+fn ring_root() {
+    crate::ring::testing::ring_root::<JubjubSha512Tai, RING_ROOT_SIZE>()
+}
+
+#[test]
+fn sw_to_te_roundtrip() {
+    use ark_ed_on_bls12_381::{JubjubConfig, SWAffine};
+
+    let org_point = testing::random_val::<SWAffine>(None);
+
+    let te_point = map_sw_to_te::<JubjubConfig>(&org_point).unwrap();
+    assert!(te_point.is_on_curve());
+
+    let sw_point = map_te_to_sw::<JubjubConfig>(&te_point).unwrap();
+    assert!(sw_point.is_on_curve());
+
+    assert_eq!(org_point, sw_point);
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<JubjubSha512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/jubjub_sha512_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Jubjub_SHA-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<JubjubSha512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/jubjub_sha512_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Jubjub_SHA-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
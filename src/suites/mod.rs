@@ -4,8 +4,41 @@ pub(crate) mod testing;
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
 
+#[cfg(feature = "ed25519")]
+pub mod ed25519_rfc_9381;
+
+#[cfg(feature = "ed25519")]
+pub mod ed25519_ell2;
+
+#[cfg(feature = "ed25519")]
+pub mod ristretto255;
+
 #[cfg(feature = "secp256r1")]
 pub mod secp256;
 
+#[cfg(feature = "secp256r1")]
+pub mod secp256_sswu;
+
+#[cfg(feature = "secp384r1")]
+pub mod secp384;
+
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+
+#[cfg(feature = "keccak")]
+pub mod secp256k1_keccak;
+
 #[cfg(feature = "bandersnatch")]
 pub mod bandersnatch;
+
+#[cfg(feature = "jubjub")]
+pub mod jubjub;
+
+#[cfg(feature = "baby-jubjub")]
+pub mod baby_jubjub;
+
+#[cfg(feature = "pasta")]
+pub mod pasta;
+
+#[cfg(feature = "bls12-381")]
+pub mod bls12_381;
@@ -0,0 +1,244 @@
+//! `ECVRF Pasta BLAKE2b-512 TAI` suites.
+//!
+//! Configuration:
+//!
+//! * `suite_string` = b"Pallas_BLAKE2b-512_TAI" for Pallas.
+//! * `suite_string` = b"Vesta_BLAKE2b-512_TAI" for Vesta.
+//!
+//! - The EC group <G> is the Pallas (resp. Vesta) elliptic curve, in Short
+//!   Weierstrass form `y^2 = x^3 + 5`, with finite field and curve parameters as
+//!   specified by the [Pasta curves](https://github.com/zcash/pasta) repository.
+//!   For this group, `fLen` = `qLen` = $32$ and `cofactor` = $1$.
+//!
+//! - The group generator G in <G> is defined as follows:
+//!   - G.x = -1
+//!   - G.y = 2
+//!
+//! * `cLen` = 32.
+//!
+//! * The key pair generation primitive is `PK = sk * G`, with x the secret
+//!   key scalar and `G` the group generator. In this ciphersuite, the secret
+//!   scalar x is equal to the secret key scalar sk.
+//!
+//! * encode_to_curve_salt = PS_string (point_to_string(PK)).
+//!
+//! * The ECVRF_nonce_generation function is as specified in Section 5.4.2.2
+//!   of RFC-9381.
+//!
+//! * The int_to_string function encodes into the 32 bytes little endian
+//!   representation.
+//!
+//! * The string_to_int function decodes from the 32 bytes little endian
+//!   representation.
+//!
+//! * The point_to_string function converts a point in <G> to an octet
+//!   string as done by the `pasta_curves` crate (Zcash, Halo2). The x
+//!   coordinate is encoded using int_to_string function and the most
+//!   significant bit of the last octet is used to keep track of the
+//!   y's parity. The point at infinity is encoded as 32 zero octets.
+//!
+//! * The string_to_point function decodes the point encoded according to
+//!   `point_to_string` procedure. This function MUST outputs "INVALID" if
+//!   the octet string does not decode to a point on G.
+//!
+//! * The hash function Hash is BLAKE2b-512 as specified in
+//!   [RFC7693](https://www.rfc-editor.org/rfc/rfc7693), with hLen = 64.
+//!
+//! * The `ECVRF_encode_to_curve` function uses the *try and increment* method
+//!   described in section 5.4.1.1 of [RFC-9381](https://datatracker.ietf.org/doc/rfc9381).
+//!
+//! Pasta curves are not pairing friendly, thus ring VRF is not supported.
+
+use crate::*;
+use ark_ff::MontFp;
+
+pub mod pallas {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct PallasBlake2b512Tai;
+
+    suite_types!(PallasBlake2b512Tai);
+
+    impl Suite for PallasBlake2b512Tai {
+        const SUITE_ID: &'static [u8] = b"Pallas_BLAKE2b-512_TAI";
+        const CHALLENGE_LEN: usize = 32;
+
+        type Affine = ark_pallas::Affine;
+        type Hasher = blake2::Blake2b512;
+        type Codec = codec::PastaCodec;
+    }
+
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for PallasBlake2b512Tai {
+        const BLINDING_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "8778704488135214024638307691164048077138385038885104568817782525046235864744"
+            );
+            const Y: BaseField = MontFp!(
+                "12974790688806238288795600232526183319917627104811352435367846234131740503465"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+
+    #[cfg(test)]
+    suite_tests!(PallasBlake2b512Tai);
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn blinding_base_derivation() {
+        pedersen::testing::blinding_base_derivation::<PallasBlake2b512Tai>();
+    }
+}
+
+pub mod vesta {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct VestaBlake2b512Tai;
+
+    suite_types!(VestaBlake2b512Tai);
+
+    impl Suite for VestaBlake2b512Tai {
+        const SUITE_ID: &'static [u8] = b"Vesta_BLAKE2b-512_TAI";
+        const CHALLENGE_LEN: usize = 32;
+
+        type Affine = ark_vesta::Affine;
+        type Hasher = blake2::Blake2b512;
+        type Codec = codec::PastaCodec;
+    }
+
+    #[cfg(feature = "pedersen")]
+    impl pedersen::PedersenSuite for VestaBlake2b512Tai {
+        const BLINDING_BASE: AffinePoint = {
+            const X: BaseField = MontFp!(
+                "26614248166907081744427390111583169325373447929069612744580572396140170982104"
+            );
+            const Y: BaseField = MontFp!(
+                "7125108314225220738258101099740643918858979219601148280527153992387866203882"
+            );
+            AffinePoint::new_unchecked(X, Y)
+        };
+    }
+
+    #[cfg(test)]
+    suite_tests!(VestaBlake2b512Tai);
+
+    #[cfg(feature = "pedersen")]
+    #[test]
+    fn blinding_base_derivation() {
+        pedersen::testing::blinding_base_derivation::<VestaBlake2b512Tai>();
+    }
+}
+
+#[test]
+fn codec_identity() {
+    use ark_ec::AffineRepr;
+    use pallas::{AffinePoint, PallasBlake2b512Tai};
+
+    let buf = codec::point_encode::<PallasBlake2b512Tai>(&AffinePoint::zero());
+    assert_eq!(buf, [0; 32]);
+    let pt = codec::point_decode::<PallasBlake2b512Tai>(&buf).unwrap();
+    assert!(pt.is_zero());
+
+    // Buffers with the wrong length are rejected.
+    assert!(codec::point_decode::<PallasBlake2b512Tai>(&[0; 33]).is_err());
+    assert!(codec::point_decode::<PallasBlake2b512Tai>(&[0; 31]).is_err());
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf_pallas {
+    use super::pallas::*;
+    use crate::testing;
+
+    type V = crate::ietf::testing::TestVector<PallasBlake2b512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/pallas_blake2b512_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Pallas_BLAKE2b-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen_pallas {
+    use super::pallas::*;
+    use crate::testing;
+
+    type V = crate::pedersen::testing::TestVector<PallasBlake2b512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/pallas_blake2b512_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Pallas_BLAKE2b-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf_vesta {
+    use super::vesta::*;
+    use crate::testing;
+
+    type V = crate::ietf::testing::TestVector<VestaBlake2b512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/vesta_blake2b512_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Vesta_BLAKE2b-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen_vesta {
+    use super::vesta::*;
+    use crate::testing;
+
+    type V = crate::pedersen::testing::TestVector<VestaBlake2b512Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/vesta_blake2b512_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Vesta_BLAKE2b-512_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
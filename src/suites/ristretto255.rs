@@ -0,0 +1,373 @@
+//! `ECVRF Ristretto255 SHA-512` suite.
+//!
+//! Configuration:
+//!
+//! * `suite_string` = b"Ristretto255_SHA-512_R255MAP".
+//!
+//! - The EC group <G> is the ristretto255 prime order group, as specified by
+//!   [RFC-9496](https://datatracker.ietf.org/doc/rfc9496), built on top of the
+//!   edwards25519 curve. For this group, `fLen` = `qLen` = $32$ and there is no
+//!   cofactor.
+//!
+//! * `cLen` = 16.
+//!
+//! * The key pair generation primitive is `PK = sk * G`, with x the secret
+//!   key scalar and `G` the group generator. In this ciphersuite, the secret
+//!   scalar x is equal to the secret key scalar sk.
+//!
+//! * encode_to_curve_salt = PS_string (point_to_string(PK)).
+//!
+//! * The ECVRF_nonce_generation function is as specified in Section 5.4.2.2
+//!   of RFC-9381.
+//!
+//! * The int_to_string function encodes into the 32 bytes little endian
+//!   representation.
+//!
+//! * The string_to_int function decodes from the 32 bytes little endian
+//!   representation.
+//!
+//! * The point_to_string and string_to_point functions are the ristretto255
+//!   `Encode` and `Decode` functions specified in sections 4.3.2 and 4.3.1 of
+//!   RFC-9496. The string_to_point function outputs "INVALID" if the octet string
+//!   is not the canonical encoding of a group element.
+//!
+//! * The hash function Hash is SHA-512 as specified in
+//!   [RFC6234](https://www.rfc-editor.org/rfc/rfc6234), with hLen = 64.
+//!
+//! * The `ECVRF_encode_to_curve` function is the ristretto255 hash to group
+//!   procedure (section 4.3.4 of RFC-9496, Elligator based), fed with 64 bytes
+//!   obtained via `expand_message_xmd` (section 5.3.1 of [RFC-9380](https://datatracker.ietf.org/doc/rfc9380)),
+//!   with `h2c_suite_ID_string` = `"ristretto255_XMD:SHA-512_R255MAP_RO_"`
+//!   and domain separation tag `DST = "ECVRF_" || h2c_suite_ID_string || suite_string`.
+//!
+//! Group elements are internally represented by the edwards25519 point of the
+//! Ristretto equivalence class which belongs to the prime order subgroup.
+
+use crate::*;
+use ark_ff::{biginteger::BigInteger, Field, MontFp, One, Zero};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Ristretto255Sha512;
+
+suite_types!(Ristretto255Sha512);
+
+impl Suite for Ristretto255Sha512 {
+    const SUITE_ID: &'static [u8] = b"Ristretto255_SHA-512_R255MAP";
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = ark_ed25519::EdwardsAffine;
+    type Hasher = sha2::Sha512;
+    type Codec = RistrettoCodec;
+
+    /// Hash data to a group element using ristretto255 hash to group (RFC-9496).
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint> {
+        // Domain Separation Tag := "ECVRF_" || h2c_suite_ID_string || suite_string
+        let h2c_suite_id: &[u8] = b"ristretto255_XMD:SHA-512_R255MAP_RO_";
+        let dst = [&b"ECVRF_"[..], h2c_suite_id, Self::SUITE_ID].concat();
+        let uniform_bytes = utils::expand_message_xmd::<sha2::Sha512>(data, &dst, 64)?;
+        Some(one_way_map(&uniform_bytes))
+    }
+
+    /// The ristretto255 group has prime order, the point is hashed as is.
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Ristretto255Sha512 {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "48419098677506520190588453153299843871969336706267662158659099548745528510099"
+        );
+        const Y: BaseField = MontFp!(
+            "35855145386287627761204259773222724314255367500242630801162236894714873391886"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+/// Ristretto255 codec (RFC-9496).
+///
+/// Little endian. Points encoded as the canonical ristretto255 32 bytes string.
+///
+/// Decoded points are mapped to the representative of the Ristretto equivalence
+/// class which belongs to the prime order subgroup.
+pub struct RistrettoCodec;
+
+impl Codec<Ristretto255Sha512> for RistrettoCodec {
+    const BIG_ENDIAN: bool = false;
+
+    /// Encode as specified by RFC-9496 section 4.3.2.
+    fn point_encode(pt: &AffinePoint, buf: &mut Vec<u8>) {
+        let one = BaseField::one();
+        let (x0, y0) = (pt.x, pt.y);
+        let t0 = x0 * y0;
+
+        let u1 = (one + y0) * (one - y0);
+        let u2 = x0 * y0;
+        let (_, invsqrt) = sqrt_ratio_m1(one, u1 * u2.square());
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t0;
+        let ix0 = x0 * SQRT_M1;
+        let iy0 = y0 * SQRT_M1;
+        let enchanted_denominator = den1 * INVSQRT_A_MINUS_D;
+        let rotate = is_negative(&(t0 * z_inv));
+        let (x, mut y, den_inv) = if rotate {
+            (iy0, ix0, enchanted_denominator)
+        } else {
+            (x0, y0, den2)
+        };
+        if is_negative(&(x * z_inv)) {
+            y = -y;
+        }
+        let s = abs(den_inv * (one - y));
+        s.serialize_compressed(buf).unwrap();
+    }
+
+    /// Decode as specified by RFC-9496 section 4.3.1.
+    fn point_decode(buf: &[u8]) -> Result<AffinePoint, Error> {
+        let one = BaseField::one();
+        if buf.len() != 32 {
+            return Err(Error::InvalidEncoding);
+        }
+        // Fails if `s` is not canonically encoded.
+        let s = BaseField::deserialize_compressed(buf)?;
+        if is_negative(&s) {
+            return Err(Error::InvalidEncoding);
+        }
+        let ss = s.square();
+        let u1 = one - ss;
+        let u2 = one + ss;
+        let u2_sqr = u2.square();
+        let v = -(D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = sqrt_ratio_m1(one, v * u2_sqr);
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+        let x = abs((s + s) * den_x);
+        let y = u1 * den_y;
+        let t = x * y;
+        if !was_square || is_negative(&t) || y.is_zero() {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(torsion_free(AffinePoint::new_unchecked(x, y)))
+    }
+
+    fn scalar_encode(sc: &ScalarField, buf: &mut Vec<u8>) {
+        sc.serialize_compressed(buf).unwrap();
+    }
+
+    fn scalar_decode(buf: &[u8]) -> ScalarField {
+        ScalarField::from_le_bytes_mod_order(buf)
+    }
+}
+
+// Edwards `d` curve parameter.
+const D: BaseField =
+    MontFp!("37095705934669439343138083508754565189542113879843219016388785533085940283555");
+// sqrt(-1)
+const SQRT_M1: BaseField =
+    MontFp!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
+// sqrt(a*d - 1)
+const SQRT_AD_MINUS_ONE: BaseField =
+    MontFp!("25063068953384623474111414158702152701244531502492656460079210482610430750235");
+// 1/sqrt(a - d)
+const INVSQRT_A_MINUS_D: BaseField =
+    MontFp!("54469307008909316920995813868745141605393597292927456921205312896311721017578");
+// 1 - d^2
+const ONE_MINUS_D_SQ: BaseField =
+    MontFp!("1159843021668779879193775521855586647937357759715417654439879720876111806838");
+// (d - 1)^2
+const D_MINUS_ONE_SQ: BaseField =
+    MontFp!("40440834346308536858101042469323190826248399146238708352240133220865137265952");
+// 1/8 mod l
+const COFACTOR_INV: ScalarField =
+    MontFp!("2713877091499598330239944961141122840321418634767465352250731601857045344121");
+
+#[inline(always)]
+fn is_negative(x: &BaseField) -> bool {
+    x.into_bigint().is_odd()
+}
+
+#[inline(always)]
+fn abs(x: BaseField) -> BaseField {
+    if is_negative(&x) {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Representative of the point equivalence class in the prime order subgroup.
+///
+/// Ristretto points differ from their representative by a 4-torsion point, which
+/// is killed by the cofactor multiplication.
+fn torsion_free(pt: AffinePoint) -> AffinePoint {
+    (pt.mul_by_cofactor() * COFACTOR_INV).into_affine()
+}
+
+/// Square root of a ratio as specified by RFC-9496 section 4.2.
+///
+/// Returns `(was_square, sqrt(u/v))` if `u/v` is square, otherwise
+/// `(false, sqrt(SQRT_M1 * u/v))`. The returned root is non-negative.
+fn sqrt_ratio_m1(u: BaseField, v: BaseField) -> (bool, BaseField) {
+    // (p - 5) / 8
+    const EXP: [u64; 4] = [
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0fffffffffffffff,
+    ];
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    let mut r = (u * v3) * (u * v7).pow(EXP);
+    let check = v * r.square();
+
+    let correct_sign_sqrt = check == u;
+    let flipped_sign_sqrt = check == -u;
+    let flipped_sign_sqrt_i = check == -u * SQRT_M1;
+    if flipped_sign_sqrt || flipped_sign_sqrt_i {
+        r *= SQRT_M1;
+    }
+    (correct_sign_sqrt || flipped_sign_sqrt, abs(r))
+}
+
+/// Elligator based map as specified by RFC-9496 section 4.3.4 (`MAP`).
+fn ristretto_map(t: BaseField) -> AffinePoint {
+    let one = BaseField::one();
+    let r = SQRT_M1 * t.square();
+    let u = (r + one) * ONE_MINUS_D_SQ;
+    let v = (-one - r * D) * (r + D);
+
+    let (was_square, mut s) = sqrt_ratio_m1(u, v);
+    let c = if was_square {
+        -one
+    } else {
+        s = -abs(s * t);
+        r
+    };
+    let n = c * (r - one) * D_MINUS_ONE_SQ - v;
+
+    let w0 = (s + s) * v;
+    let w1 = n * SQRT_AD_MINUS_ONE;
+    let w2 = one - s.square();
+    let w3 = one + s.square();
+
+    // Extended coordinates: (w0*w3, w2*w1, w1*w3, w0*w2)
+    let z_inv = (w1 * w3)
+        .inverse()
+        .expect("Ristretto map outputs a finite point. Q.E.D.");
+    AffinePoint::new_unchecked(w0 * w3 * z_inv, w2 * w1 * z_inv)
+}
+
+/// Ristretto255 one-way map from 64 uniform bytes (RFC-9496 section 4.3.4).
+fn one_way_map(bytes: &[u8]) -> AffinePoint {
+    let map = |b: &[u8]| {
+        let mut b = b.to_vec();
+        b[31] &= 0x7f;
+        ristretto_map(BaseField::from_le_bytes_mod_order(&b))
+    };
+    let pt = map(&bytes[..32]) + map(&bytes[32..64]);
+    torsion_free(pt.into_affine())
+}
+
+#[cfg(test)]
+suite_tests!(Ristretto255Sha512);
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<Ristretto255Sha512>();
+}
+
+#[test]
+fn codec_rfc_9496() {
+    // RFC-9496 appendix A.1: multiples of the generator
+    let vectors = [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+        "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+    ];
+    let g = Ristretto255Sha512::generator();
+    for (i, v) in vectors.iter().enumerate() {
+        let pt = (g * ScalarField::from(i as u64)).into_affine();
+        let buf = codec::point_encode::<Ristretto255Sha512>(&pt);
+        assert_eq!(hex::encode(&buf), *v);
+        assert_eq!(codec::point_decode::<Ristretto255Sha512>(&buf).unwrap(), pt);
+    }
+
+    // Non canonical (negative) field element
+    let mut buf = hex::decode(vectors[1]).unwrap();
+    buf[0] |= 1;
+    assert_eq!(
+        codec::point_decode::<Ristretto255Sha512>(&buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        codec::point_decode::<Ristretto255Sha512>(&buf[..31]),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+fn one_way_map_rfc_9496() {
+    // RFC-9496 appendix A.3
+    let bytes = hex::decode(
+        "5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1\
+         4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+    )
+    .unwrap();
+    let pt = one_way_map(&bytes);
+    assert_eq!(
+        hex::encode(codec::point_encode::<Ristretto255Sha512>(&pt)),
+        "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46"
+    );
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<Ristretto255Sha512>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/ristretto255_sha512_r255map_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Ristretto255_SHA-512_R255MAP");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<Ristretto255Sha512>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/ristretto255_sha512_r255map_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "Ristretto255_SHA-512_R255MAP");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
@@ -48,7 +48,7 @@
 //!    Section 5.4.1.1, with interpret_hash_value_as_a_point(s) =
 //!    string_to_point(0x02 || s).
 
-use crate::{pedersen::PedersenSuite, *};
+use crate::*;
 use ark_ff::MontFp;
 
 #[derive(Debug, Copy, Clone)]
@@ -76,7 +76,15 @@ impl Suite for P256Sha256Tai {
     }
 }
 
-impl PedersenSuite for P256Sha256Tai {
+#[cfg(feature = "pkcs8")]
+impl crate::pkcs8::Pkcs8Suite for P256Sha256Tai {
+    /// `prime256v1` (1.2.840.10045.3.1.7).
+    const CURVE_OID: &'static [u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for P256Sha256Tai {
+    /// Legacy constant.
     const BLINDING_BASE: AffinePoint = {
         const X: BaseField = MontFp!(
             "14043613715035732602742871684475452461130505690937359323850445130419175222977"
@@ -88,7 +96,7 @@ impl PedersenSuite for P256Sha256Tai {
     };
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ietf"))]
 mod test_vectors_ietf {
     use super::*;
 
@@ -122,7 +130,7 @@ mod test_vectors_ietf {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "pedersen"))]
 mod test_vectors_pedersen {
     use super::*;
 
@@ -144,3 +152,38 @@ mod test_vectors_pedersen {
         testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
     }
 }
+
+#[cfg(all(test, feature = "pkcs8"))]
+mod test_pkcs8 {
+    use super::*;
+
+    #[test]
+    fn pkcs8_roundtrip() {
+        crate::pkcs8::testing::pkcs8_roundtrip::<P256Sha256Tai>();
+    }
+
+    // Generated with OpenSSL for the RFC-9381 example secret key.
+    #[test]
+    fn pkcs8_fixture() {
+        let sk = hex::decode("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+            .unwrap();
+        let secret = Secret::from_scalar(codec::scalar_decode::<P256Sha256Tai>(&sk));
+        let spki = hex::decode(
+            "3059301306072a8648ce3d020106082a8648ce3d0301070342000460fed4ba255a9d31c961eb74c6356d\
+             68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3\
+             c294d4462299",
+        )
+        .unwrap();
+        let pkcs8 = hex::decode(
+            "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b0201010420c9afa9d845ba\
+             75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721a1440342000460fed4ba255a9d31c961eb\
+             74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e\
+             9f5177a3c294d4462299",
+        )
+        .unwrap();
+        assert_eq!(secret.public().to_public_key_der(), spki);
+        assert_eq!(secret.to_pkcs8_der(), pkcs8);
+        assert_eq!(Secret::from_pkcs8_der(&pkcs8).unwrap(), secret);
+        assert_eq!(Public::from_public_key_der(&spki).unwrap(), secret.public());
+    }
+}
@@ -0,0 +1,131 @@
+//! `ECVRF-P256-SHA256-SSWU` suite.
+//!
+//! Configuration (RFC-9381):
+//!
+//! *  suite_string = 0x02.
+//!
+//! *  The EC group G is the NIST P-256 elliptic curve, with the finite
+//!    field and curve parameters as specified in Section 3.2.1.3 of
+//!    [SP-800-186] and Section 2.6 of [RFC5114].  For this group, fLen =
+//!    qLen = 32 and cofactor = 1.
+//!
+//! *  cLen = 16.
+//!
+//! *  The key pair generation primitive is specified in Section 3.2.1 of
+//!    [SECG1] (q, B, SK, and Y in this document correspond to n, G, d,
+//!    and Q in Section 3.2.1 of [SECG1]).  In this ciphersuite, the
+//!    secret scalar x is equal to the secret key SK.
+//!
+//! *  encode_to_curve_salt = PK_string.
+//!
+//! *  The ECVRF_nonce_generation function is as specified in
+//!    Section 5.4.2.1.
+//!
+//! *  The int_to_string function is the I2OSP function specified in
+//!    Section 4.1 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The string_to_int function is the OS2IP function specified in
+//!    Section 4.2 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The point_to_string function converts a point on E to an octet
+//!    string according to the encoding specified in Section 2.3.3 of
+//!    [SECG1] with point compression on.  This implies that
+//!    ptLen = fLen + 1 = 33.
+//!
+//! *  The string_to_point function converts an octet string to a point
+//!    on E according to the encoding specified in Section 2.3.4 of
+//!    [SECG1].  This function MUST output "INVALID" if the octet string
+//!    does not decode to a point on the curve E.
+//!
+//! *  The hash function Hash is SHA-256 as specified in [RFC6234], with
+//!    hLen = 32.
+//!
+//! *  The ECVRF_encode_to_curve function is as specified in
+//!    Section 5.4.1.2, with `h2c_suite_ID_string` = `"P256_XMD:SHA-256_SSWU_NU_"`.
+//!    The hash-to-curve procedure runs in constant time.
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct P256Sha256Sswu;
+
+suite_types!(P256Sha256Sswu);
+
+#[cfg(test)]
+suite_tests!(P256Sha256Sswu);
+
+impl Suite for P256Sha256Sswu {
+    const SUITE_ID: &'static [u8] = &[0x02];
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = ark_secp256r1::Affine;
+    type Hasher = sha2::Sha256;
+    type Codec = codec::Sec1Codec;
+
+    fn nonce(sk: &ScalarField, pt: Input) -> ScalarField {
+        utils::nonce_rfc_6979::<Self>(sk, &pt.0)
+    }
+
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint> {
+        let h2c_suite_id = b"P256_XMD:SHA-256_SSWU_NU_";
+        utils::hash_to_curve_sswu_nu_rfc_9380::<Self>(data, h2c_suite_id)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl crate::pkcs8::Pkcs8Suite for P256Sha256Sswu {
+    /// `prime256v1` (1.2.840.10045.3.1.7).
+    const CURVE_OID: &'static [u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for P256Sha256Sswu {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "69180862799136954974847014470256339377796385108338183257267056861068949013616"
+        );
+        const Y: BaseField = MontFp!(
+            "108662520951577857485440903333453776622045101140880791274775888067785222593931"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+impl arkworks::sswu::SWUConfig for ark_secp256r1::Config {
+    const ZETA: ark_secp256r1::Fq = MontFp!("-10");
+}
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<P256Sha256Sswu>();
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<P256Sha256Sswu>;
+
+    // Vectors from RFC-9381
+    const TEST_VECTORS_FILE_RFC_9381: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/secp256_sha256_sswu_ietf_vectors_rfc_9381.json"
+    );
+
+    #[test]
+    fn process_rfc_9381() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE_RFC_9381);
+    }
+}
+
+#[cfg(all(test, feature = "pkcs8"))]
+mod test_pkcs8 {
+    use super::*;
+
+    #[test]
+    fn pkcs8_roundtrip() {
+        crate::pkcs8::testing::pkcs8_roundtrip::<P256Sha256Sswu>();
+    }
+}
@@ -0,0 +1,152 @@
+//! `ECVRF-SECP256K1-SHA256-TAI` suite.
+//!
+//! Configuration (RFC-9381 conventions, not a standardized ciphersuite):
+//!
+//! *  suite_string = b"secp256k1_SHA-256_TAI".
+//!
+//! *  The EC group G is the secp256k1 elliptic curve, with the finite
+//!    field and curve parameters as specified in Section 2.4.1 of [SECG2].
+//!    For this group, fLen = qLen = 32 and cofactor = 1.
+//!
+//! *  cLen = 16.
+//!
+//! *  The key pair generation primitive is specified in Section 3.2.1 of
+//!    [SECG1]. In this ciphersuite, the secret scalar x is equal to the
+//!    secret key SK. Thus secp256k1 wallet keys can be used as-is.
+//!
+//! *  encode_to_curve_salt = PK_string.
+//!
+//! *  The ECVRF_nonce_generation function is as specified in
+//!    Section 5.4.2.1.
+//!
+//! *  The int_to_string function is the I2OSP function specified in
+//!    Section 4.1 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The string_to_int function is the OS2IP function specified in
+//!    Section 4.2 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The point_to_string function converts a point on E to an octet
+//!    string according to the encoding specified in Section 2.3.3 of
+//!    [SECG1] with point compression on.  This implies that
+//!    ptLen = fLen + 1 = 33.
+//!
+//! *  The string_to_point function converts an octet string to a point
+//!    on E according to the encoding specified in Section 2.3.4 of
+//!    [SECG1].  This function MUST output "INVALID" if the octet string
+//!    does not decode to a point on the curve E.
+//!
+//! *  The hash function Hash is SHA-256 as specified in [RFC6234], with
+//!    hLen = 32.
+//!
+//! *  The ECVRF_encode_to_curve function is as specified in
+//!    Section 5.4.1.1, with interpret_hash_value_as_a_point(s) =
+//!    string_to_point(0x02 || s).
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Secp256k1Sha256Tai;
+
+suite_types!(Secp256k1Sha256Tai);
+
+#[cfg(test)]
+suite_tests!(Secp256k1Sha256Tai);
+
+impl Suite for Secp256k1Sha256Tai {
+    const SUITE_ID: &'static [u8] = b"secp256k1_SHA-256_TAI";
+    const CHALLENGE_LEN: usize = 16;
+
+    type Affine = ark_secp256k1::Affine;
+    type Hasher = sha2::Sha256;
+    type Codec = codec::Sec1Codec;
+
+    fn nonce(sk: &ScalarField, pt: Input) -> ScalarField {
+        utils::nonce_rfc_6979::<Self>(sk, &pt.0)
+    }
+
+    fn data_to_point(data: &[u8]) -> Option<AffinePoint> {
+        utils::hash_to_curve_tai_rfc_9381::<Self>(data)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl crate::pkcs8::Pkcs8Suite for Secp256k1Sha256Tai {
+    /// `secp256k1` (1.3.132.0.10).
+    const CURVE_OID: &'static [u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for Secp256k1Sha256Tai {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!(
+            "100642584114832204928867849889009086834138085354077596929213811875217152441232"
+        );
+        const Y: BaseField = MontFp!(
+            "63043583370873489087084229749043100285965477355690774295582808556299501361542"
+        );
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<Secp256k1Sha256Tai>();
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<Secp256k1Sha256Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/secp256k1_sha256_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "secp256k1_SHA-256_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<Secp256k1Sha256Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/secp256k1_sha256_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "secp256k1_SHA-256_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pkcs8"))]
+mod test_pkcs8 {
+    use super::*;
+
+    #[test]
+    fn pkcs8_roundtrip() {
+        crate::pkcs8::testing::pkcs8_roundtrip::<Secp256k1Sha256Tai>();
+    }
+}
@@ -0,0 +1,144 @@
+//! `ECVRF-P384-SHA384-TAI` suite.
+//!
+//! Configuration (RFC-9381 conventions, not a standardized ciphersuite):
+//!
+//! *  suite_string = b"secp384r1_SHA-384_TAI".
+//!
+//! *  The EC group G is the NIST P-384 elliptic curve, with the finite
+//!    field and curve parameters as specified in Section 3.2.1.4 of
+//!    [SP-800-186].  For this group, fLen = qLen = 48 and cofactor = 1.
+//!
+//! *  cLen = 24.
+//!
+//! *  The key pair generation primitive is specified in Section 3.2.1 of
+//!    [SECG1].  In this ciphersuite, the secret scalar x is equal to the
+//!    secret key SK.
+//!
+//! *  encode_to_curve_salt = PK_string.
+//!
+//! *  The ECVRF_nonce_generation function is as specified in
+//!    Section 5.4.2.1.
+//!
+//! *  The int_to_string function is the I2OSP function specified in
+//!    Section 4.1 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The string_to_int function is the OS2IP function specified in
+//!    Section 4.2 of [RFC8017].  (This is big-endian representation.)
+//!
+//! *  The point_to_string function converts a point on E to an octet
+//!    string according to the encoding specified in Section 2.3.3 of
+//!    [SECG1] with point compression on.  This implies that
+//!    ptLen = fLen + 1 = 49.
+//!
+//! *  The string_to_point function converts an octet string to a point
+//!    on E according to the encoding specified in Section 2.3.4 of
+//!    [SECG1].  This function MUST output "INVALID" if the octet string
+//!    does not decode to a point on the curve E.
+//!
+//! *  The hash function Hash is SHA-384 as specified in [RFC6234], with
+//!    hLen = 48.
+//!
+//! *  The ECVRF_encode_to_curve function is as specified in
+//!    Section 5.4.1.1, with interpret_hash_value_as_a_point(s) =
+//!    string_to_point(0x02 || s).
+
+use crate::*;
+use ark_ff::MontFp;
+
+#[derive(Debug, Copy, Clone)]
+pub struct P384Sha384Tai;
+
+suite_types!(P384Sha384Tai);
+
+#[cfg(test)]
+suite_tests!(P384Sha384Tai);
+
+impl Suite for P384Sha384Tai {
+    const SUITE_ID: &'static [u8] = b"secp384r1_SHA-384_TAI";
+    const CHALLENGE_LEN: usize = 24;
+
+    type Affine = ark_secp384r1::Affine;
+    type Hasher = sha2::Sha384;
+    type Codec = codec::Sec1Codec;
+
+    fn nonce(sk: &ScalarField, pt: Input) -> ScalarField {
+        utils::nonce_rfc_6979::<Self>(sk, &pt.0)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl crate::pkcs8::Pkcs8Suite for P384Sha384Tai {
+    /// `secp384r1` (1.3.132.0.34).
+    const CURVE_OID: &'static [u8] = &[0x2b, 0x81, 0x04, 0x00, 0x22];
+}
+
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for P384Sha384Tai {
+    const BLINDING_BASE: AffinePoint = {
+        const X: BaseField = MontFp!("6927921583311053443711943826172570158479665312434125116262291258999529545766435658441341782667324097895251966981668");
+        const Y: BaseField = MontFp!("16534906007094859712579761545020979642419927473156805953078332913146147395905970548184013558629397932609024771622852");
+        AffinePoint::new_unchecked(X, Y)
+    };
+}
+
+#[cfg(feature = "pedersen")]
+#[test]
+fn blinding_base_derivation() {
+    pedersen::testing::blinding_base_derivation::<P384Sha384Tai>();
+}
+
+#[cfg(all(test, feature = "ietf"))]
+mod test_vectors_ietf {
+    use super::*;
+
+    type V = crate::ietf::testing::TestVector<P384Sha384Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/secp384r1_sha384_tai_ietf_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "secp384r1_SHA-384_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pedersen"))]
+mod test_vectors_pedersen {
+    use super::*;
+
+    type V = crate::pedersen::testing::TestVector<P384Sha384Tai>;
+
+    const TEST_VECTORS_FILE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/secp384r1_sha384_tai_pedersen_vectors.json"
+    );
+
+    #[test]
+    #[ignore = "test vectors generator"]
+    fn generate() {
+        testing::test_vectors_generate::<V>(TEST_VECTORS_FILE, "secp384r1_SHA-384_TAI");
+    }
+
+    #[test]
+    fn process() {
+        testing::test_vectors_process::<V>(TEST_VECTORS_FILE);
+    }
+}
+
+#[cfg(all(test, feature = "pkcs8"))]
+mod test_pkcs8 {
+    use super::*;
+
+    #[test]
+    fn pkcs8_roundtrip() {
+        crate::pkcs8::testing::pkcs8_roundtrip::<P384Sha384Tai>();
+    }
+}
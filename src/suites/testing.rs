@@ -1,7 +1,7 @@
 //! Suite for testing
 
 use crate::testing as common;
-use crate::{pedersen::PedersenSuite, *};
+use crate::*;
 use ark_ff::MontFp;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -18,9 +18,15 @@ impl Suite for TestSuite {
     fn nonce(_sk: &ScalarField, _pt: Input) -> ScalarField {
         common::random_val(None)
     }
+
+    /// Output point hashed without cofactor clearing (as by the test vectors).
+    fn point_to_hash(pt: &AffinePoint) -> HashOutput<Self> {
+        utils::point_to_hash_rfc_9381::<Self>(pt)
+    }
 }
 
-impl PedersenSuite for TestSuite {
+#[cfg(feature = "pedersen")]
+impl pedersen::PedersenSuite for TestSuite {
     const BLINDING_BASE: AffinePoint = {
         const X: BaseField =
             MontFp!("1181072390894490040170698195029164902368238760122173135634802939739986120753");
@@ -34,7 +34,9 @@ macro_rules! suite_tests {
         ring_suite_tests!($suite, $build_ring);
     };
     ($suite:ident) => {
+        #[cfg(feature = "ietf")]
         ietf_suite_tests!($suite);
+        #[cfg(feature = "pedersen")]
         pedersen_suite_tests!($suite);
     };
 }
@@ -190,14 +192,9 @@ impl<S: Suite + std::fmt::Debug> TestVectorTrait for TestVector<S> {
         let pk = sk.public();
         assert_eq!(self.pk, pk.0, "public key ('pk') mismatch");
 
-        // Prepare hash_to_curve data = salt || alpha
-        // Salt is defined to be pk (adjust it to make the encoding to match)
-        let pk_bytes = codec::point_encode::<S>(&pk.0);
-        let h2c_data = [&pk_bytes[..], &self.alpha[..]].concat();
-
-        let h = S::data_to_point(&h2c_data).unwrap();
-        assert_eq!(self.h, h, "hash-to-curve ('h') mismatch");
-        let input = Input::<S>::from(h);
+        // Hash to curve data = salt || alpha, with salt defined to be pk
+        let input = Input::<S>::new_salted(&pk, &self.alpha).unwrap();
+        assert_eq!(self.h, input.0, "hash-to-curve ('h') mismatch");
 
         let output = sk.output(input);
         assert_eq!(self.gamma, output.0, "VRF pre-output ('gamma') mismatch");
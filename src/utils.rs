@@ -1,7 +1,7 @@
 use crate::*;
 
 #[allow(unused)]
-pub(crate) use crate::arkworks::{elligator2, te_sw_map};
+pub(crate) use crate::arkworks::{elligator2, sswu, te_sw_map};
 
 use ark_ec::AffineRepr;
 use ark_ff::PrimeField;
@@ -18,6 +18,8 @@ macro_rules! suite_types {
         #[allow(dead_code)]
         pub type Public = $crate::Public<$suite>;
         #[allow(dead_code)]
+        pub type Keypair = $crate::Keypair<$suite>;
+        #[allow(dead_code)]
         pub type Input = $crate::Input<$suite>;
         #[allow(dead_code)]
         pub type Output = $crate::Output<$suite>;
@@ -27,21 +29,257 @@ macro_rules! suite_types {
         pub type ScalarField = $crate::ScalarField<$suite>;
         #[allow(dead_code)]
         pub type BaseField = $crate::BaseField<$suite>;
+        #[cfg(feature = "ietf")]
         #[allow(dead_code)]
         pub type IetfProof = $crate::ietf::Proof<$suite>;
+        #[cfg(feature = "pedersen")]
         #[allow(dead_code)]
         pub type PedersenProof = $crate::pedersen::Proof<$suite>;
+        #[cfg(feature = "pedersen")]
+        #[allow(dead_code)]
+        pub type PedersenSignature = $crate::pedersen::Signature<$suite>;
         #[cfg(feature = "ring")]
         #[allow(dead_code)]
         pub type RingProof = $crate::ring::Proof<$suite>;
     };
 }
 
+// Implements `Display`, `Debug` and `FromStr` for `$type` using the hex
+// representation of the given canonical byte encoding, of fixed `$len` length.
+// With the `serde` feature, also implements `Serialize` and `Deserialize` (see
+// `serde_serialize`). With the `scale` feature, also implements the SCALE codec
+// traits, encoding as a fixed size byte array. Raw byte conversions are provided
+// via `TryFrom<&[u8]>` and `From<$type> for Vec<u8>`.
+macro_rules! bytes_encoding_impl {
+    ($type:ident<S: $bound:path>, $encode:expr, $decode:expr, $len:expr) => {
+        impl<S: $bound> core::fmt::Display for $type<S> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::utils::hex_fmt(f, &($encode)(self))
+            }
+        }
+
+        impl<S: $bound> core::fmt::Debug for $type<S> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}({})", stringify!($type), self)
+            }
+        }
+
+        impl<S: $bound> core::str::FromStr for $type<S> {
+            type Err = $crate::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                ($decode)(&$crate::utils::hex_decode(s)?[..])
+            }
+        }
+
+        impl<S: $bound> TryFrom<&[u8]> for $type<S> {
+            type Error = $crate::Error;
+
+            fn try_from(buf: &[u8]) -> Result<Self, Self::Error> {
+                ($decode)(buf)
+            }
+        }
+
+        impl<S: $bound> From<$type<S>> for ark_std::vec::Vec<u8> {
+            fn from(value: $type<S>) -> Self {
+                ($encode)(&value)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<S: $bound> serde::Serialize for $type<S> {
+            fn serialize<T: serde::Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+                $crate::utils::serde_serialize(&($encode)(self), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, S: $bound> serde::Deserialize<'de> for $type<S> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let buf = $crate::utils::serde_deserialize(deserializer)?;
+                ($decode)(&buf[..]).map_err(|_| serde::de::Error::custom("invalid encoding"))
+            }
+        }
+
+        #[cfg(feature = "scale")]
+        impl<S: $bound> parity_scale_codec::Encode for $type<S> {
+            fn size_hint(&self) -> usize {
+                ($len)()
+            }
+
+            fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+                dest.write(&($encode)(self))
+            }
+        }
+
+        #[cfg(feature = "scale")]
+        impl<S: $bound> parity_scale_codec::EncodeLike for $type<S> {}
+
+        #[cfg(feature = "scale")]
+        impl<S: $bound> parity_scale_codec::Decode for $type<S> {
+            fn decode<I: parity_scale_codec::Input>(
+                input: &mut I,
+            ) -> Result<Self, parity_scale_codec::Error> {
+                let mut buf = ark_std::vec![0; ($len)()];
+                input.read(&mut buf)?;
+                ($decode)(&buf[..]).map_err(|_| concat!("Invalid ", stringify!($type)).into())
+            }
+        }
+
+        #[cfg(feature = "scale")]
+        impl<S: $bound> parity_scale_codec::MaxEncodedLen for $type<S> {
+            fn max_encoded_len() -> usize {
+                ($len)()
+            }
+        }
+
+        #[cfg(feature = "scale")]
+        impl<S: $bound + 'static> scale_info::TypeInfo for $type<S> {
+            type Identity = Self;
+
+            fn type_info() -> scale_info::Type {
+                let len = ($len)() as u32;
+                scale_info::TypeDefArray::new(len, scale_info::meta_type::<u8>()).into()
+            }
+        }
+    };
+}
+
+pub(crate) use bytes_encoding_impl;
+
+/// Write `data` as lowercase hex.
+pub(crate) fn hex_fmt(f: &mut core::fmt::Formatter<'_>, data: &[u8]) -> core::fmt::Result {
+    data.iter().try_for_each(|b| write!(f, "{:02x}", b))
+}
+
+/// Hex `Display` wrapper.
+pub(crate) struct Hex<'a>(pub &'a [u8]);
+
+impl core::fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        hex_fmt(f, self.0)
+    }
+}
+
+/// Serialize `data` as a hex string for human readable formats and as raw
+/// bytes otherwise.
+#[cfg(feature = "serde")]
+pub(crate) fn serde_serialize<S: serde::Serializer>(
+    data: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.collect_str(&Hex(data)),
+        false => serializer.serialize_bytes(data),
+    }
+}
+
+/// Deserialize bytes serialized via `serde_serialize`.
+#[cfg(feature = "serde")]
+pub(crate) fn serde_deserialize<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    struct BytesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.write_str("a hex string or a byte array")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+            hex_decode(v).map_err(|_| E::custom("invalid hex string"))
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut buf = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                buf.push(b);
+            }
+            Ok(buf)
+        }
+    }
+
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_str(BytesVisitor),
+        false => deserializer.deserialize_bytes(BytesVisitor),
+    }
+}
+
+/// Decode a hex string, without prefix, into bytes.
+///
+/// Returns `Error::InvalidEncoding` if `s` has odd length or contains non hex digits.
+pub(crate) fn hex_decode(s: &str) -> Result<Vec<u8>, Error> {
+    let digit = |c: u8| (c as char).to_digit(16).ok_or(Error::InvalidEncoding);
+    if s.len() % 2 != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+    s.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| Ok((digit(pair[0])? << 4 | digit(pair[1])?) as u8))
+        .collect()
+}
+
 // Generic hash wrapper.
 pub(crate) fn hash<H: Digest>(data: &[u8]) -> digest::Output<H> {
     H::new().chain_update(data).finalize()
 }
 
+struct OutputLen<H>(core::marker::PhantomData<H>);
+
+impl<H: Digest> OutputLen<H> {
+    const AT_LEAST_32: () = assert!(
+        <H::OutputSize as digest::typenum::Unsigned>::USIZE >= 32,
+        "hasher output shorter than 32 bytes"
+    );
+}
+
+/// First 32 bytes of a hash.
+///
+/// Hashers with output shorter than 32 bytes are rejected at compile time.
+pub(crate) fn hash_prefix_32<H: Digest>(hash: &digest::Output<H>) -> [u8; 32] {
+    #[allow(clippy::let_unit_value)]
+    let () = OutputLen::<H>::AT_LEAST_32;
+    let mut prefix = [0; 32];
+    prefix.copy_from_slice(&hash[..32]);
+    prefix
+}
+
+/// Constant time bytes equality.
+///
+/// All the bytes are compared, without short-circuiting on the first mismatch.
+/// Length is assumed to be public.
+#[cfg(feature = "subtle")]
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    use subtle::ConstantTimeEq;
+    a.ct_eq(b).into()
+}
+
+/// Constant time bytes equality.
+///
+/// All the bytes are compared, without short-circuiting on the first mismatch.
+/// Length is assumed to be public.
+#[cfg(not(feature = "subtle"))]
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0_u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
+
 /// Generic HMAC wrapper.
 #[cfg(feature = "rfc-6979")]
 pub(crate) fn hmac<H: Digest + digest::core_api::BlockSizeUser>(sk: &[u8], data: &[u8]) -> Vec<u8> {
@@ -88,6 +326,8 @@ pub fn hash_to_curve_tai_rfc_9381<S: Suite>(data: &[u8]) -> Option<AffinePoint<S
 
     let mut buf = [S::SUITE_ID, &[DOM_SEP_FRONT], data, &[0x00, DOM_SEP_BACK]].concat();
     let ctr_pos = buf.len() - 2;
+    // The hash prefix is interpreted as an encoded point.
+    let point_len = codec::point_encoded_len::<S>();
 
     for ctr in 0..=255 {
         buf[ctr_pos] = ctr;
@@ -99,6 +339,7 @@ pub fn hash_to_curve_tai_rfc_9381<S: Suite>(data: &[u8]) -> Option<AffinePoint<S
         if S::Codec::BIG_ENDIAN {
             buf.insert(0, 0x02);
         }
+        buf.truncate(point_len);
 
         if let Ok(pt) = codec::point_decode::<S>(&buf[..]) {
             let pt = pt.clear_cofactor();
@@ -110,6 +351,47 @@ pub fn hash_to_curve_tai_rfc_9381<S: Suite>(data: &[u8]) -> Option<AffinePoint<S
     None
 }
 
+/// Hash to curve as defined by RFC 9380 (random oracle variant) using the map `M`.
+///
+/// Builds the RFC 9380 `hash_to_curve` pipeline (`hash_to_field` using
+/// `expand_message_xmd` with `Suite::Hasher`, `map_to_curve`, `clear_cofactor`)
+/// from any Arkworks `MapToCurve` implementation. For example:
+/// - `ark_ec::hashing::curve_maps::wb::WBMap` (Wahby-Boneh, e.g. BLS12-381).
+/// - `ark_ec::hashing::curve_maps::swu::SWUMap`.
+/// - The crate Elligator2 map (see `hash_to_curve_ell2_rfc_9380`).
+///
+/// Domain Separation Tag := "ECVRF_" || h2c_suite_ID_string || suite_string.
+///
+/// The input `data` is defined to be `salt || alpha` according to the RFC 9281.
+pub fn hash_to_curve_rfc_9380<S: Suite, M>(
+    data: &[u8],
+    h2c_suite_id: &[u8],
+) -> Option<AffinePoint<S>>
+where
+    <S as Suite>::Hasher: Default + Clone + FixedOutputReset + 'static,
+    M: ark_ec::hashing::map_to_curve_hasher::MapToCurve<<AffinePoint<S> as AffineRepr>::Group>,
+{
+    use ark_ec::hashing::HashToCurve;
+    const SEC_PARAM: usize = 128;
+
+    // Domain Separation Tag := "ECVRF_" || h2c_suite_ID_string || suite_string
+    let dst: Vec<_> = b"ECVRF_"
+        .iter()
+        .chain(h2c_suite_id)
+        .chain(S::SUITE_ID)
+        .cloned()
+        .collect();
+
+    let hasher = ark_ec::hashing::map_to_curve_hasher::MapToCurveBasedHasher::<
+        <AffinePoint<S> as AffineRepr>::Group,
+        ark_ff::field_hashers::DefaultFieldHasher<<S as Suite>::Hasher, SEC_PARAM>,
+        M,
+    >::new(&dst)
+    .ok()?;
+
+    hasher.hash(data).ok()
+}
+
 /// Elligator2 method as defined by RFC 9380 and further refined in RFC 9381 section 5.4.1.2.
 ///
 /// Implements ECVRF_encode_to_curve using one of the several hash-to-curve options defined
@@ -128,7 +410,31 @@ where
     crate::arkworks::elligator2::Elligator2Map<crate::CurveConfig<S>>:
         ark_ec::hashing::map_to_curve_hasher::MapToCurve<<AffinePoint<S> as AffineRepr>::Group>,
 {
-    use ark_ec::hashing::HashToCurve;
+    hash_to_curve_rfc_9380::<S, crate::arkworks::elligator2::Elligator2Map<crate::CurveConfig<S>>>(
+        data,
+        h2c_suite_id,
+    )
+}
+
+/// Simplified SWU method as defined by RFC 9380 and further refined in RFC 9381 section 5.4.1.2.
+///
+/// Implements ECVRF_encode_to_curve using the `encode_to_curve` (non-uniform) option
+/// defined in [RFC9380] for Short Weierstrass curves. The specific choice of the
+/// hash-to-curve option (called the Suite ID in [RFC9380]) is given by the
+/// h2c_suite_ID_string parameter.
+///
+/// The input `data` is defined to be `salt || alpha` according to the RFC 9281.
+pub fn hash_to_curve_sswu_nu_rfc_9380<S: Suite>(
+    data: &[u8],
+    h2c_suite_id: &[u8],
+) -> Option<AffinePoint<S>>
+where
+    <S as Suite>::Hasher: digest::core_api::BlockSizeUser,
+    crate::CurveConfig<S>: crate::arkworks::sswu::SWUConfig,
+    crate::arkworks::sswu::SWUMap<crate::CurveConfig<S>>:
+        ark_ec::hashing::map_to_curve_hasher::MapToCurve<<AffinePoint<S> as AffineRepr>::Group>,
+{
+    use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
     const SEC_PARAM: usize = 128;
 
     // Domain Separation Tag := "ECVRF_" || h2c_suite_ID_string || suite_string
@@ -139,22 +445,44 @@ where
         .cloned()
         .collect();
 
-    let hasher = ark_ec::hashing::map_to_curve_hasher::MapToCurveBasedHasher::<
-        <AffinePoint<S> as AffineRepr>::Group,
-        ark_ff::field_hashers::DefaultFieldHasher<<S as Suite>::Hasher, SEC_PARAM>,
-        crate::arkworks::elligator2::Elligator2Map<crate::CurveConfig<S>>,
-    >::new(&dst)
-    .ok()?;
+    let u = hash_to_field::<BaseField<S>, ExpandXmd<S::Hasher>>(data, &dst, 1, SEC_PARAM)?;
+
+    let map = crate::arkworks::sswu::SWUMap::<crate::CurveConfig<S>>::new().ok()?;
+    let res = map.map_to_curve(u[0]).ok()?;
+    Some(res.clear_cofactor())
+}
 
-    let res = hasher.hash(data).ok()?;
-    Some(res)
+/// Single byte length prefixes of the suite identification strings.
+///
+/// The lengths are checked at compile time, thus a suite with a `Suite::SUITE_ID`
+/// or a `Suite::CONTEXT` longer than 255 bytes fails to build as soon as any of
+/// the encodings using these prefixes is instantiated for it.
+pub(crate) struct LenPrefix<S>(core::marker::PhantomData<S>);
+
+impl<S: Suite> LenPrefix<S> {
+    /// `Suite::SUITE_ID` length prefix.
+    pub const SUITE_ID: u8 = len_prefix(S::SUITE_ID);
+    /// `Suite::CONTEXT` length prefix.
+    pub const CONTEXT: u8 = len_prefix(S::CONTEXT);
+}
+
+const fn len_prefix(data: &[u8]) -> u8 {
+    assert!(data.len() <= u8::MAX as usize, "length doesn't fit in one byte");
+    data.len() as u8
 }
 
 /// Challenge generation according to RFC-9381 section 5.4.3.
+///
+/// A non empty `Suite::CONTEXT` is hashed as `len(context) || context` right
+/// after the domain separator.
 pub fn challenge_rfc_9381<S: Suite>(pts: &[&AffinePoint<S>], ad: &[u8]) -> ScalarField<S> {
     const DOM_SEP_START: u8 = 0x02;
     const DOM_SEP_END: u8 = 0x00;
     let mut buf = [S::SUITE_ID, &[DOM_SEP_START]].concat();
+    if !S::CONTEXT.is_empty() {
+        buf.push(LenPrefix::<S>::CONTEXT);
+        buf.extend_from_slice(S::CONTEXT);
+    }
     pts.iter().for_each(|p| {
         S::Codec::point_encode(p, &mut buf);
     });
@@ -164,16 +492,180 @@ pub fn challenge_rfc_9381<S: Suite>(pts: &[&AffinePoint<S>], ad: &[u8]) -> Scala
     ScalarField::<S>::from_be_bytes_mod_order(hash)
 }
 
-/// Point to a hash according to RFC-9381 section 5.2.
+/// Point to a hash according to RFC-9381 section 5.2, hashing the point as is.
+///
+/// Equivalent to `point_to_hash_rfc_9381_with_cofactor(pt, false)`.
 pub fn point_to_hash_rfc_9381<S: Suite>(pt: &AffinePoint<S>) -> HashOutput<S> {
+    point_to_hash_rfc_9381_with_cofactor::<S>(pt, false)
+}
+
+/// Point to a hash according to RFC-9381 section 5.2.
+///
+/// RFC-9381 hashes `cofactor * Gamma`. As the VRF output is already in the prime
+/// order subgroup, the multiplication is optional and enabled by `mul_by_cofactor`
+/// (required to match the outputs of other RFC-9381 implementations).
+pub fn point_to_hash_rfc_9381_with_cofactor<S: Suite>(
+    pt: &AffinePoint<S>,
+    mul_by_cofactor: bool,
+) -> HashOutput<S> {
     const DOM_SEP_START: u8 = 0x03;
     const DOM_SEP_END: u8 = 0x00;
     let mut buf = [S::SUITE_ID, &[DOM_SEP_START]].concat();
-    S::Codec::point_encode(pt, &mut buf);
+    if mul_by_cofactor {
+        S::Codec::point_encode(&pt.mul_by_cofactor(), &mut buf);
+    } else {
+        S::Codec::point_encode(pt, &mut buf);
+    }
     buf.push(DOM_SEP_END);
     hash::<S::Hasher>(&buf)
 }
 
+/// Prefix used to hash oversized DSTs (RFC-9380 section 5.3.3).
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expand message as specified by RFC-9380 section 5.3.1.
+///
+/// DSTs longer than 255 bytes are hashed as specified by section 5.3.3.
+///
+/// Returns `None` if the requested length is out of bounds.
+pub fn expand_message_xmd<H: Digest + digest::core_api::BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len: usize,
+) -> Option<Vec<u8>> {
+    let b_len = <H as Digest>::output_size();
+    let r_len = H::block_size();
+
+    let ell = len.div_ceil(b_len);
+    if ell > 255 || len > u16::MAX as usize {
+        return None;
+    }
+    let dst_hash;
+    let dst = if dst.len() > 255 {
+        dst_hash = H::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &dst_hash[..]
+    } else {
+        dst
+    };
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b0 = H::new()
+        .chain_update(ark_std::vec![0; r_len])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut bi = H::new()
+        .chain_update(&b0)
+        .chain_update([1])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut out = bi.to_vec();
+    for i in 2..=ell {
+        let xored: Vec<u8> = b0.iter().zip(bi.iter()).map(|(a, b)| a ^ b).collect();
+        bi = H::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        out.extend_from_slice(&bi);
+    }
+    out.truncate(len);
+    Some(out)
+}
+
+/// Expand message as specified by RFC-9380 section 5.3.2.
+///
+/// `sec_param` is the target security level in bits (e.g. 128 for SHAKE128),
+/// used to hash DSTs longer than 255 bytes as specified by section 5.3.3.
+///
+/// Returns `None` if the requested length is out of bounds.
+pub fn expand_message_xof<X: Default + digest::Update + digest::ExtendableOutput>(
+    msg: &[u8],
+    dst: &[u8],
+    len: usize,
+    sec_param: usize,
+) -> Option<Vec<u8>> {
+    use digest::{ExtendableOutput, Update, XofReader};
+
+    if len > u16::MAX as usize {
+        return None;
+    }
+    let mut dst_hash = ark_std::vec![0; (2 * sec_param).div_ceil(8)];
+    let dst = if dst.len() > 255 {
+        X::default()
+            .chain(OVERSIZE_DST_PREFIX)
+            .chain(dst)
+            .finalize_xof()
+            .read(&mut dst_hash);
+        &dst_hash[..]
+    } else {
+        dst
+    };
+    if dst.len() > 255 {
+        return None;
+    }
+
+    let mut out = ark_std::vec![0; len];
+    X::default()
+        .chain(msg)
+        .chain((len as u16).to_be_bytes())
+        .chain(dst)
+        .chain([dst.len() as u8])
+        .finalize_xof()
+        .read(&mut out);
+    Some(out)
+}
+
+/// Message expansion backend used by `hash_to_field` (RFC-9380 section 5.3).
+pub trait ExpandMessage {
+    /// Expand `msg` to `len` bytes using the domain separation tag `dst`.
+    ///
+    /// `sec_param` is the target security level in bits (`k`).
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize, sec_param: usize) -> Option<Vec<u8>>;
+}
+
+/// `expand_message_xmd` backend using the hash function `H`.
+pub struct ExpandXmd<H>(core::marker::PhantomData<H>);
+
+impl<H: Digest + digest::core_api::BlockSizeUser> ExpandMessage for ExpandXmd<H> {
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize, _sec_param: usize) -> Option<Vec<u8>> {
+        expand_message_xmd::<H>(msg, dst, len)
+    }
+}
+
+/// `expand_message_xof` backend using the extendable output function `X`.
+pub struct ExpandXof<X>(core::marker::PhantomData<X>);
+
+impl<X: Default + digest::Update + digest::ExtendableOutput> ExpandMessage for ExpandXof<X> {
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize, sec_param: usize) -> Option<Vec<u8>> {
+        expand_message_xof::<X>(msg, dst, len, sec_param)
+    }
+}
+
+/// Hash to field as specified by RFC-9380 section 5.2.
+///
+/// Hashes `msg` to `count` elements of the prime field `F`, using the message
+/// expansion backend `E` and the target security level `sec_param` (in bits).
+/// Each element is obtained by reducing `L = ceil((ceil(log2(p)) + k) / 8)`
+/// uniform bytes modulo the field characteristic.
+///
+/// Returns `None` if message expansion fails (e.g. output length out of bounds).
+pub fn hash_to_field<F: PrimeField, E: ExpandMessage>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    sec_param: usize,
+) -> Option<Vec<F>> {
+    let l = (F::MODULUS_BIT_SIZE as usize + sec_param).div_ceil(8);
+    let uniform = E::expand_message(msg, dst, count * l, sec_param)?;
+    Some(uniform.chunks_exact(l).map(F::from_be_bytes_mod_order).collect())
+}
+
 /// Nonce generation according to RFC-9381 section 5.4.2.2.
 ///
 /// This procedure is based on section 5.1.6 of RFC 8032: "Edwards-Curve Digital
@@ -181,23 +673,34 @@ pub fn point_to_hash_rfc_9381<S: Suite>(pt: &AffinePoint<S>) -> HashOutput<S> {
 ///
 /// The algorithm generate the nonce value in a deterministic pseudorandom fashion.
 ///
-/// # Panics
+/// The procedure is defined for 64 bytes hashes (e.g. SHA-512). Shorter hashes are
+/// supported as well: the whole secret key hash is used as nonce prefix, and the
+/// nonce hash is widened to 64 bytes by hashing the same data with a counter
+/// suffix, so that the modular reduction bias stays negligible.
 ///
-/// This function panics if `Suite::Hasher` output is less than 64 bytes.
+/// `Suite::CONTEXT` is appended to the encoded input point.
 pub fn nonce_rfc_8032<S: Suite>(sk: &ScalarField<S>, input: &AffinePoint<S>) -> ScalarField<S> {
-    assert!(
-        S::Hasher::output_size() >= 64,
-        "Suite::Hasher output is required to be >= 64 bytes"
-    );
+    const WIDE_LEN: usize = 64;
 
-    let raw = codec::scalar_encode::<S>(sk);
-    let sk_hash = &hash::<S::Hasher>(&raw)[32..];
+    let mut raw = codec::scalar_encode::<S>(sk);
+    let mut sk_hash = hash::<S::Hasher>(&raw);
+    raw.as_mut_slice().zeroize();
+    let prefix_offset = sk_hash.len().saturating_sub(32);
 
     let raw = codec::point_encode::<S>(input);
-    let v = [sk_hash, &raw[..]].concat();
-    let h = &hash::<S::Hasher>(&v)[..];
+    let mut v = [&sk_hash[prefix_offset..], &raw[..], S::CONTEXT].concat();
+    sk_hash.as_mut_slice().zeroize();
+    let mut h = hash::<S::Hasher>(&v).to_vec();
+    let mut ctr = 0_u8;
+    while h.len() < WIDE_LEN {
+        h.extend_from_slice(&hash::<S::Hasher>(&[&v[..], &[ctr]].concat()));
+        ctr += 1;
+    }
+    v.as_mut_slice().zeroize();
 
-    S::Codec::scalar_decode(h)
+    let nonce = S::Codec::scalar_decode(&h);
+    h.as_mut_slice().zeroize();
+    nonce
 }
 
 /// Nonce generation according to RFC 9381 section 5.4.2.1.
@@ -207,36 +710,72 @@ pub fn nonce_rfc_8032<S: Suite>(sk: &ScalarField<S>, input: &AffinePoint<S>) ->
 /// Algorithm (ECDSA)".
 ///
 /// The algorithm generate the nonce value in a deterministic pseudorandom fashion.
+///
+/// The `bits2int` conversion keeps the leftmost `qlen` bits of the candidate,
+/// with `qlen` the scalar field modulus bit length.
+///
+/// `Suite::CONTEXT` is appended to the encoded input point before hashing.
 #[cfg(feature = "rfc-6979")]
 pub fn nonce_rfc_6979<S: Suite>(sk: &ScalarField<S>, input: &AffinePoint<S>) -> ScalarField<S>
 where
     S::Hasher: digest::core_api::BlockSizeUser,
 {
-    let raw = codec::point_encode::<S>(input);
+    use ark_ff::Zero;
+
+    let raw = [&codec::point_encode::<S>(input)[..], S::CONTEXT].concat();
     let h1 = hash::<S::Hasher>(&raw);
 
-    let v = [1; 32];
-    let k = [0; 32];
+    let hlen = S::Hasher::output_size();
+    let v = ark_std::vec![1; hlen];
+    let k = ark_std::vec![0; hlen];
 
     // K = HMAC_K(V || 0x00 || int2octets(x) || bits2octets(h1))
-    let x = codec::scalar_encode::<S>(sk);
-    let raw = [&v[..], &[0x00], &x[..], &h1[..]].concat();
+    let mut x = codec::scalar_encode::<S>(sk);
+    let mut raw = [&v[..], &[0x00], &x[..], &h1[..]].concat();
     let k = hmac::<S::Hasher>(&k, &raw);
+    raw.as_mut_slice().zeroize();
 
     // V = HMAC_K(V)
     let v = hmac::<S::Hasher>(&k, &v);
 
     // K = HMAC_K(V || 0x01 || int2octets(x) || bits2octets(h1))
-    let raw = [&v[..], &[0x01], &x[..], &h1[..]].concat();
-    let k = hmac::<S::Hasher>(&k, &raw);
+    let mut raw = [&v[..], &[0x01], &x[..], &h1[..]].concat();
+    let mut k = hmac::<S::Hasher>(&k, &raw);
+    raw.as_mut_slice().zeroize();
+    x.as_mut_slice().zeroize();
 
     // V = HMAC_K(V)
-    let v = hmac::<S::Hasher>(&k, &v);
-
-    // TODO: loop until 1 < k < q
-    let v = hmac::<S::Hasher>(&k, &v);
-
-    S::Codec::scalar_decode(&v)
+    let mut v = hmac::<S::Hasher>(&k, &v);
+
+    // Loop until a candidate in [1, q-1] is found
+    let qlen = ScalarField::<S>::zero().compressed_size();
+    loop {
+        // T = V || HMAC_K(V) || ... (until tlen >= qlen)
+        let mut t = Vec::with_capacity(qlen + v.len());
+        while t.len() < qlen {
+            v = hmac::<S::Hasher>(&k, &v);
+            t.extend_from_slice(&v);
+        }
+        // k = bits2int(T)
+        let mut t = t[..qlen].to_vec();
+        let excess = 8 * qlen - ScalarField::<S>::MODULUS_BIT_SIZE as usize;
+        if excess != 0 {
+            for i in (0..qlen).rev() {
+                let carry = if i > 0 { t[i - 1] << (8 - excess) } else { 0 };
+                t[i] = (t[i] >> excess) | carry;
+            }
+        }
+        t.reverse();
+        if let Ok(nonce) = ScalarField::<S>::deserialize_compressed(&t[..]) {
+            if !nonce.is_zero() {
+                return nonce;
+            }
+        }
+        // K = HMAC_K(V || 0x00)
+        k = hmac::<S::Hasher>(&k, &[&v[..], &[0x00]].concat());
+        // V = HMAC_K(V)
+        v = hmac::<S::Hasher>(&k, &v);
+    }
 }
 
 #[cfg(test)]
@@ -244,6 +783,24 @@ mod tests {
     use super::*;
     use crate::suites::testing::TestSuite;
 
+    #[test]
+    fn point_to_hash_cofactor_clearing() {
+        let pt = TestSuite::generator();
+        let plain = point_to_hash_rfc_9381::<TestSuite>(&pt);
+        assert_eq!(
+            plain,
+            point_to_hash_rfc_9381_with_cofactor::<TestSuite>(&pt, false)
+        );
+        assert_ne!(
+            plain,
+            point_to_hash_rfc_9381_with_cofactor::<TestSuite>(&pt, true)
+        );
+        assert_eq!(
+            point_to_hash_rfc_9381::<TestSuite>(&pt.mul_by_cofactor()),
+            point_to_hash_rfc_9381_with_cofactor::<TestSuite>(&pt, true)
+        );
+    }
+
     #[test]
     fn hash_to_curve_tai_works() {
         let pt = hash_to_curve_tai_rfc_9381::<TestSuite>(b"hello world").unwrap();
@@ -251,4 +808,72 @@ mod tests {
         assert!(pt.is_on_curve());
         assert!(pt.is_in_correct_subgroup_assuming_on_curve())
     }
+
+    #[test]
+    fn nonce_rfc_8032_short_hash() {
+        // `TestSuite` hasher is SHA-256.
+        let sk = ScalarField::<TestSuite>::from(42);
+        let pt = TestSuite::generator();
+        let nonce = nonce_rfc_8032::<TestSuite>(&sk, &pt);
+        assert_eq!(nonce, nonce_rfc_8032::<TestSuite>(&sk, &pt));
+        assert_ne!(nonce, ScalarField::<TestSuite>::from(0));
+        let other = ScalarField::<TestSuite>::from(43);
+        assert_ne!(nonce, nonce_rfc_8032::<TestSuite>(&other, &pt));
+    }
+
+    // Vector from RFC-9380 appendix J.5.1 (edwards25519_XMD:SHA-512_ELL2_RO_)
+    #[test]
+    fn hash_to_field_xmd_sha512() {
+        type F = ark_ed25519::Fq;
+        const DST: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let u = hash_to_field::<F, ExpandXmd<sha2::Sha512>>(b"", DST, 2, 128).unwrap();
+        let expected = [
+            "03fef4813c8cb5f98c6eef88fae174e6e7d5380de2b007799ac7ee712d203f3a",
+            "780bdddd137290c8f589dc687795aafae35f6b674668d92bf92ae793e6a60c75",
+        ]
+        .map(|h| F::from_be_bytes_mod_order(&hex::decode(h).unwrap()));
+        assert_eq!(u, expected);
+    }
+
+    // Vectors from RFC-9380 appendix K.1
+    #[test]
+    fn expand_message_xmd_sha256() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let out = expand_message_xmd::<sha2::Sha256>(b"", DST, 0x20).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        let out = expand_message_xmd::<sha2::Sha256>(b"abc", DST, 0x20).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+        let out = expand_message_xmd::<sha2::Sha256>(b"", DST, 0x80).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+             e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+             eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+             c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+        );
+        assert!(expand_message_xmd::<sha2::Sha256>(b"", DST, 256 * 32).is_none());
+    }
+
+    // Vectors from RFC-9380 appendix K.6
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn expand_message_xof_shake128() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        let out = expand_message_xof::<sha3::Shake128>(b"", DST, 0x20, 128).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"
+        );
+        let out = expand_message_xof::<sha3::Shake128>(b"abc", DST, 0x20, 128).unwrap();
+        assert_eq!(
+            hex::encode(out),
+            "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"
+        );
+    }
 }